@@ -0,0 +1,100 @@
+use crate::{
+    interfaces::contract::CarShareTokenTrait,
+    methods::registry::{settle_burn, settle_transfer},
+    storage::{
+        balance::extend_balance_ttl,
+        metadata::{extend_instance_ttl, read_admin, read_car, write_admin, write_car},
+    },
+};
+use soroban_sdk::{contract, contractimpl, token::TokenInterface, Address, Env, String};
+use stellar_tokens::fungible::Base;
+
+/// Participaciones sobre los ingresos de un auto. Las emite el contrato rent-a-car, que reparte
+/// los ingresos entre los titulares; el token le avisa antes de cada cambio de saldo. Saldos y
+/// autorizaciones los lleva `stellar_tokens::fungible`.
+#[contract]
+pub struct CarShareToken;
+
+#[contractimpl]
+impl CarShareTokenTrait for CarShareToken {
+    fn __constructor(env: Env, admin: Address, car: Address, name: String, symbol: String) {
+        write_admin(&env, &admin);
+        write_car(&env, &car);
+        Base::set_metadata(&env, 0, name, symbol);
+    }
+
+    fn admin(env: Env) -> Address {
+        read_admin(&env)
+    }
+
+    fn car(env: Env) -> Address {
+        read_car(&env)
+    }
+
+    fn total_supply(env: Env) -> i128 {
+        Base::total_supply(&env)
+    }
+
+    fn mint(env: Env, to: Address, amount: i128) {
+        // El emisor liquida al titular por su cuenta antes de emitir
+        read_admin(&env).require_auth();
+
+        extend_instance_ttl(&env);
+        Base::mint(&env, &to, amount);
+        extend_balance_ttl(&env, &to);
+    }
+}
+
+#[contractimpl]
+impl TokenInterface for CarShareToken {
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        Base::allowance(&env, &from, &spender)
+    }
+
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        Base::approve(&env, &from, &spender, amount, expiration_ledger);
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        extend_instance_ttl(&env);
+        Base::balance(&env, &id)
+    }
+
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        extend_instance_ttl(&env);
+        settle_transfer(&env, &from, &to);
+        Base::transfer(&env, &from, &to, amount);
+        extend_balance_ttl(&env, &to);
+    }
+
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        extend_instance_ttl(&env);
+        settle_transfer(&env, &from, &to);
+        Base::transfer_from(&env, &spender, &from, &to, amount);
+        extend_balance_ttl(&env, &to);
+    }
+
+    fn burn(env: Env, from: Address, amount: i128) {
+        extend_instance_ttl(&env);
+        settle_burn(&env, &from);
+        Base::burn(&env, &from, amount);
+    }
+
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        extend_instance_ttl(&env);
+        settle_burn(&env, &from);
+        Base::burn_from(&env, &spender, &from, amount);
+    }
+
+    fn decimals(env: Env) -> u32 {
+        Base::decimals(&env)
+    }
+
+    fn name(env: Env) -> String {
+        Base::name(&env)
+    }
+
+    fn symbol(env: Env) -> String {
+        Base::symbol(&env)
+    }
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::{Address, Env, String};
+
+pub trait CarShareTokenTrait {
+    fn __constructor(env: Env, admin: Address, car: Address, name: String, symbol: String);
+    fn admin(env: Env) -> Address;
+    fn car(env: Env) -> Address;
+    fn total_supply(env: Env) -> i128;
+    fn mint(env: Env, to: Address, amount: i128);
+}
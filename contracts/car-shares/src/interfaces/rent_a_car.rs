@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+/// Contrato que emitió las participaciones y reparte los ingresos del auto. Antes de que cambie
+/// un saldo, el token le informa los saldos previos de cada parte para que liquide lo acumulado.
+#[contractclient(name = "ShareRegistryClient")]
+pub trait ShareRegistry {
+    fn settle_share_holders(env: Env, car: Address, holders: Vec<(Address, i128)>);
+}
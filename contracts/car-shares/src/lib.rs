@@ -0,0 +1,10 @@
+#![no_std]
+
+mod contract;
+mod interfaces;
+mod tests;
+mod storage;
+mod methods;
+
+pub use contract::{CarShareToken, CarShareTokenClient};
+pub use interfaces::rent_a_car::ShareRegistry;
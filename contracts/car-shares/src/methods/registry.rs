@@ -0,0 +1,26 @@
+use soroban_sdk::{vec, Address, Env};
+use stellar_tokens::fungible::Base;
+
+use crate::{
+    interfaces::rent_a_car::ShareRegistryClient,
+    storage::metadata::{read_admin, read_car},
+};
+
+/// Le pide al contrato emisor que liquide los ingresos de `from` y `to` con sus saldos actuales,
+/// antes de moverlos
+pub fn settle_transfer(env: &Env, from: &Address, to: &Address) {
+    let holders = vec![
+        env,
+        (from.clone(), Base::balance(env, from)),
+        (to.clone(), Base::balance(env, to)),
+    ];
+
+    ShareRegistryClient::new(env, &read_admin(env)).settle_share_holders(&read_car(env), &holders);
+}
+
+/// Igual que `settle_transfer`, para participaciones que se queman
+pub fn settle_burn(env: &Env, from: &Address) {
+    let holders = vec![env, (from.clone(), Base::balance(env, from))];
+
+    ShareRegistryClient::new(env, &read_admin(env)).settle_share_holders(&read_car(env), &holders);
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::{Address, Env};
+use stellar_tokens::fungible::{StorageKey, BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+
+/// `stellar_tokens` renueva el TTL de un saldo al leerlo, pero no al crearlo: quien recibe
+/// participaciones por primera vez queda con el TTL mínimo de la red hasta su próxima operación
+pub(crate) fn extend_balance_ttl(env: &Env, holder: &Address) {
+    let key = StorageKey::Balance(holder.clone());
+
+    if env.storage().persistent().has(&key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+    }
+}
@@ -0,0 +1,28 @@
+use soroban_sdk::{Address, Env};
+use stellar_tokens::fungible::{INSTANCE_EXTEND_AMOUNT, INSTANCE_TTL_THRESHOLD};
+
+use crate::storage::types::storage::DataKey;
+
+pub(crate) fn read_admin(env: &Env) -> Address {
+    env.storage().instance().get(&DataKey::Admin).unwrap()
+}
+
+pub(crate) fn write_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub(crate) fn read_car(env: &Env) -> Address {
+    env.storage().instance().get(&DataKey::Car).unwrap()
+}
+
+pub(crate) fn write_car(env: &Env, car: &Address) {
+    env.storage().instance().set(&DataKey::Car, car);
+}
+
+/// `stellar_tokens` renueva el TTL de cada saldo; la instancia, con la metadata y el supply, se
+/// renueva en cada operación con saldos
+pub(crate) fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_EXTEND_AMOUNT);
+}
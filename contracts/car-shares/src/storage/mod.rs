@@ -0,0 +1,3 @@
+pub mod types;
+pub mod metadata;
+pub mod balance;
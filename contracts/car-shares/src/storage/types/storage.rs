@@ -0,0 +1,9 @@
+use soroban_sdk::contracttype;
+
+/// Saldos, autorizaciones, supply y metadata los guarda `stellar_tokens::fungible`
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,                      // contrato rent-a-car que emite las participaciones
+    Car,                        // auto cuyos ingresos representan las participaciones
+}
@@ -0,0 +1,62 @@
+use soroban_sdk::{
+    contract, contractimpl, contracttype, testutils::Address as _, Address, Env, String, Vec,
+};
+
+use crate::{contract::CarShareTokenClient, CarShareToken, ShareRegistry};
+
+#[derive(Clone)]
+#[contracttype]
+enum MockKey {
+    Settled,
+}
+
+/// Registro de prueba: guarda los saldos que el token le informó en la última liquidación
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl ShareRegistry for MockRegistry {
+    fn settle_share_holders(env: Env, _car: Address, holders: Vec<(Address, i128)>) {
+        env.storage().instance().set(&MockKey::Settled, &holders);
+    }
+}
+
+pub fn last_settled(env: &Env, registry: &Address) -> Vec<(Address, i128)> {
+    env.as_contract(registry, || {
+        env.storage().instance().get(&MockKey::Settled).unwrap_or(Vec::new(env))
+    })
+}
+
+pub struct ContractTest<'a> {
+    pub env: Env,
+    pub contract: CarShareTokenClient<'a>,
+    pub registry: Address,
+    pub car: Address,
+}
+
+impl<'a> ContractTest<'a> {
+    pub fn setup() -> Self {
+        let env = Env::default();
+
+        let registry = env.register(MockRegistry, ());
+        let car = Address::generate(&env);
+
+        let contract_id = env.register(
+            CarShareToken,
+            (
+                &registry,
+                &car,
+                String::from_str(&env, "Fiat 600 shares"),
+                String::from_str(&env, "FIAT"),
+            ),
+        );
+        let contract = CarShareTokenClient::new(&env, &contract_id);
+
+        ContractTest {
+            env,
+            contract,
+            registry,
+            car,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+#![cfg(test)]
+
+pub mod config;
+pub mod token;
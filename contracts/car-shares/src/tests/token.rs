@@ -0,0 +1,137 @@
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    vec, Address, String,
+};
+use stellar_tokens::fungible::{StorageKey, BALANCE_EXTEND_AMOUNT, INSTANCE_TTL_THRESHOLD};
+use crate::tests::config::contract::{last_settled, ContractTest};
+
+#[test]
+pub fn test_metadata() {
+    let ContractTest { env, contract, registry, car } = ContractTest::setup();
+
+    assert_eq!(contract.admin(), registry);
+    assert_eq!(contract.car(), car);
+    assert_eq!(contract.name(), String::from_str(&env, "Fiat 600 shares"));
+    assert_eq!(contract.symbol(), String::from_str(&env, "FIAT"));
+    assert_eq!(contract.decimals(), 0);
+    assert_eq!(contract.total_supply(), 0);
+}
+
+#[test]
+pub fn test_admin_mints() {
+    let ContractTest { env, contract, registry, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let holder = Address::generate(&env);
+    contract.mint(&holder, &1_000);
+
+    assert_eq!(env.auths()[0].0, registry);
+    assert_eq!(contract.balance(&holder), 1_000);
+    assert_eq!(contract.total_supply(), 1_000);
+}
+
+#[test]
+pub fn test_transfer_settles_previous_balances() {
+    let ContractTest { env, contract, registry, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let holder = Address::generate(&env);
+    let investor = Address::generate(&env);
+    contract.mint(&holder, &1_000);
+
+    contract.transfer(&holder, &investor, &400);
+
+    // El emisor recibe los saldos previos a la transferencia
+    assert_eq!(
+        last_settled(&env, &registry),
+        vec![&env, (holder.clone(), 1_000), (investor.clone(), 0)]
+    );
+    assert_eq!(contract.balance(&holder), 600);
+    assert_eq!(contract.balance(&investor), 400);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+pub fn test_transfer_above_balance() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let holder = Address::generate(&env);
+    let investor = Address::generate(&env);
+    contract.mint(&holder, &1_000);
+
+    contract.transfer(&holder, &investor, &1_001);
+}
+
+#[test]
+pub fn test_transfer_from_spends_allowance() {
+    let ContractTest { env, contract, registry, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let holder = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    let investor = Address::generate(&env);
+    contract.mint(&holder, &1_000);
+
+    contract.approve(&holder, &marketplace, &500, &(env.ledger().sequence() + 100));
+    contract.transfer_from(&marketplace, &holder, &investor, &300);
+
+    assert_eq!(contract.allowance(&holder, &marketplace), 200);
+    assert_eq!(contract.balance(&investor), 300);
+    assert_eq!(
+        last_settled(&env, &registry),
+        vec![&env, (holder, 1_000), (investor, 0)]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+pub fn test_allowance_expires() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let holder = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    contract.mint(&holder, &1_000);
+
+    contract.approve(&holder, &marketplace, &500, &(env.ledger().sequence() + 10));
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 11);
+
+    assert_eq!(contract.allowance(&holder, &marketplace), 0);
+    contract.transfer_from(&marketplace, &holder, &marketplace, &100);
+}
+
+#[test]
+pub fn test_burn_reduces_supply() {
+    let ContractTest { env, contract, registry, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let holder = Address::generate(&env);
+    contract.mint(&holder, &1_000);
+
+    contract.burn(&holder, &250);
+
+    assert_eq!(last_settled(&env, &registry), vec![&env, (holder.clone(), 1_000)]);
+    assert_eq!(contract.balance(&holder), 750);
+    assert_eq!(contract.total_supply(), 750);
+}
+
+#[test]
+pub fn test_balance_ttl_extended() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let holder = Address::generate(&env);
+    contract.mint(&holder, &1_000);
+
+    let ttl = env.as_contract(&contract.address, || {
+        env.storage().persistent().get_ttl(&StorageKey::Balance(holder.clone()))
+    });
+    assert_eq!(ttl, BALANCE_EXTEND_AMOUNT);
+
+    // Pasado el TTL mínimo de la red el saldo sigue vivo y cada consulta lo renueva
+    env.ledger().with_mut(|li| li.sequence_number += INSTANCE_TTL_THRESHOLD);
+    assert_eq!(contract.balance(&holder), 1_000);
+    env.ledger().with_mut(|li| li.sequence_number += INSTANCE_TTL_THRESHOLD);
+    assert_eq!(contract.balance(&holder), 1_000);
+}
@@ -0,0 +1,15 @@
+pub(crate) const DAY_IN_SECONDS: u64 = 86_400;
+pub(crate) const MONTH_IN_SECONDS: u64 = 30 * DAY_IN_SECONDS;
+pub(crate) const MAX_PAGE_SIZE: u32 = 50;
+pub(crate) const PICKUP_WINDOW: u64 = DAY_IN_SECONDS;
+pub(crate) const CLAIM_WINDOW: u64 = 3 * DAY_IN_SECONDS;
+pub(crate) const CLAIM_RESOLUTION_PERIOD: u64 = 7 * DAY_IN_SECONDS;
+pub(crate) const MAX_ADDITIONAL_DRIVERS: u32 = 3;
+pub(crate) const DEFAULT_OVERDUE_GRACE_PERIOD: u64 = DAY_IN_SECONDS;
+pub(crate) const DEFAULT_OVERDUE_PENALTY_PERCENTAGE: u32 = 100;
+pub(crate) const DEFAULT_KEEPER_REWARD_PERCENTAGE: u32 = 10;
+pub(crate) const PAYOUT_DESTINATION_TIMELOCK: u64 = 2 * DAY_IN_SECONDS;
+pub(crate) const TOTAL_SHARE_BPS: u32 = 10_000;
+pub(crate) const SHARE_REVENUE_PRECISION: i128 = 1_000_000_000_000;
+pub(crate) const CAR_TOKEN_NAME: &str = "Rent a Car";
+pub(crate) const CAR_TOKEN_SYMBOL: &str = "CAR";
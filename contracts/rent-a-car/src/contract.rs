@@ -1,19 +1,115 @@
 use crate::{
+    constants::{
+        CAR_TOKEN_NAME, CAR_TOKEN_SYMBOL, CLAIM_RESOLUTION_PERIOD, CLAIM_WINDOW, DAY_IN_SECONDS,
+        MAX_ADDITIONAL_DRIVERS, PAYOUT_DESTINATION_TIMELOCK, PICKUP_WINDOW,
+    },
     events,
     interfaces::contract::RentACarContractTrait,
-    methods::{public::get_car_status::get_car_status, token::token::token_transfer},
+    methods::{
+        admin::listing_approver::require_listing_approver,
+        car_token::{
+            approval::{approve_car_token, approve_car_token_operator, can_spend_car_token},
+            ownership::{burn_car_token, car_holder, mint_car_token, require_car_holder, token_holder},
+            transfer::transfer_car_token,
+        },
+        payout::{
+            destination::{effective_payout_destination, resolve_payout_recipient},
+            revenue_split::validate_beneficiaries,
+        },
+        pricing::{
+            promo_code::validate_promo_code,
+            quote::{calculate_extension_price, quote_rental, validate_pricing_rules},
+        },
+        public::{
+            get_car_status::get_car_status,
+            get_rentals::{get_rentals_by_car, get_rentals_by_renter},
+        },
+        rental::{
+            create_rental::{collect_payment, create_rental},
+            deposit::{has_unsettled_rentals, settle_deposit},
+            earnings::release_earnings,
+            insurance::pay_claim_from_insurance,
+            mileage::{charge_mileage_overage, record_reporter_odometer},
+            overdue::charge_late_penalty,
+            reservation::cancel_reservation,
+            status::transition_rental,
+        },
+        shares::{dividends::settle_holding, share_token::ShareTokenClient},
+        token::token::token_transfer,
+        verification::credential::require_verified_renter,
+    },
     storage::{
         admin::{has_admin, read_admin, write_admin},
+        arbiter::{read_arbiter, write_arbiter},
+        auto_payout::{has_auto_payout, remove_auto_payout, write_auto_payout},
+        beneficiary::{
+            read_beneficiaries, read_beneficiary_balance, remove_beneficiaries, write_beneficiaries,
+            write_beneficiary_balance,
+        },
+        attestation::{read_attestation, remove_attestation, write_attestation},
+        blocklist::{is_blocked, remove_blocked, write_blocked},
         car::{has_car, read_car, remove_car, write_car},
+        car_token::{read_car_token, read_token_car},
+        claim::{read_claim, write_claim},
         comission::{read_accumulated_commission, write_accumulated_commission},
         contract_balance::{read_contract_balance, write_contract_balance},
-        rental::write_rental,
-        structs::{car::Car, rental::Rental},
+        driver::{read_drivers, write_drivers},
+        fleet_manager::{remove_fleet_manager, write_fleet_manager},
+        handover::{read_pickup, read_return, write_pickup, write_return},
+        odometer::{read_odometer, write_odometer},
+        overdue_policy::{read_overdue_policy, write_overdue_policy},
+        payout_destination::{read_payout_destination, write_payout_destination},
+        organization::{
+            has_organization, read_org_member, read_organization, read_treasury_organization,
+            remove_org_member, write_org_member, write_organization, write_treasury_organization,
+        },
+        insurance::{
+            read_insurance_config, read_insurance_pool_balance, write_insurance_config,
+            write_insurance_pool_balance,
+        },
+        pricing_rules::{read_pricing_rules, remove_pricing_rules, write_pricing_rules},
+        promo_code::{has_promo_code, read_promo_code, remove_promo_code, write_promo_code},
+        rental::{
+            read_car_active_rental, read_rental, remove_car_active_rental, write_rental,
+        },
+        reputation::{has_rated, read_reputation, write_rated, write_reputation},
+        shares::{
+            read_car_shares, read_share_holding, read_share_token, write_car_shares,
+            write_share_holding, write_share_token,
+        },
+        structs::{
+            attestation::Attestation,
+            beneficiary::Beneficiary,
+            car::Car,
+            claim::Claim,
+            credential::Credential,
+            handover::{Handover, HandoverReport},
+            insurance::InsuranceConfig,
+            organization::{MemberLimit, Organization, SpendingPeriod},
+            odometer::OdometerReadings,
+            overdue_policy::OverduePolicy,
+            payout_destination::PayoutDestination,
+            pricing_rules::PricingRules,
+            promo_code::{Discount, PromoCode},
+            rental::{PaymentSource, Rental},
+            rental_options::RentalOptions,
+            reputation::Reputation,
+            shares::CarShares,
+            telematics::{TelematicsSnapshot, TelematicsStatus},
+        },
+        telematics::{
+            read_telematics_reporter, read_telematics_snapshot, remove_telematics_reporter,
+            write_telematics_reporter, write_telematics_snapshot,
+        },
         token::write_token,
-        types::{car_status::CarStatus, error::Error},
+        types::{car_status::CarStatus, error::Error, rental_status::RentalStatus},
+        verifier::{
+            is_verifier, remove_verifier, remove_verifier_key, write_verifier, write_verifier_key,
+        },
     },
 };
-use soroban_sdk::{contract, contractimpl, Address, Env};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
+use stellar_tokens::non_fungible::Base;
 
 #[contract]
 pub struct RentACarContract;
@@ -25,13 +121,21 @@ impl RentACarContractTrait for RentACarContract {
             return Err(Error::AdminTokenConflict);
         }
 
-        if has_admin(&env) {
+        if has_admin(env) {
             return Err(Error::ContractInitialized);
         }
 
         write_admin(env, &admin);
         write_token(env, &token);
 
+        // El URI de cada NFT es la metadata de su auto, así que no hay URI base
+        Base::set_metadata(
+            env,
+            String::from_str(env, ""),
+            String::from_str(env, CAR_TOKEN_NAME),
+            String::from_str(env, CAR_TOKEN_SYMBOL),
+        );
+
         events::contract::contract_initialized(env, admin, token);
         Ok(())
     }
@@ -54,7 +158,7 @@ impl RentACarContractTrait for RentACarContract {
         }
 
         // Validar que la comisión sea un porcentaje válido (0-100)
-        if commission < 0 || commission > 100 {
+        if !(0..=100).contains(&commission) {
             return Err(Error::CommissionTooHigh);
         }
 
@@ -63,153 +167,363 @@ impl RentACarContractTrait for RentACarContract {
             car_status: CarStatus::Available,
             available_to_withdraw: 0,
             comission_to_admin: commission,
+            metadata: String::from_str(env, ""),
+            deposit: 0,
+            min_renter_rating: 0,
+            requires_verification: false,
+            required_license_class: 0,
+            included_km_per_day: 0,
+            overage_fee_per_km: 0,
         };
 
         write_car(env, &owner, &car);
-        events::add_car::car_added(env, owner, price_per_day);
+        events::add_car::car_added(env, owner.clone(), price_per_day);
+        mint_car_token(env, &owner)?;
         Ok(())
     }
 
-    fn get_car_status(env: &Env, owner: Address) -> Result<CarStatus, Error> {
-        get_car_status(env, &owner)
+    fn request_listing(
+        env: &Env,
+        owner: Address,
+        metadata: String,
+        price_per_day: i128,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        if price_per_day <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        if has_car(env, &owner) {
+            return Err(Error::CarAlreadyExist);
+        }
+
+        // La comisión se define cuando el admin o un gestor de flota aprueba la publicación
+        let car = Car {
+            price_per_day,
+            car_status: CarStatus::PendingApproval,
+            available_to_withdraw: 0,
+            comission_to_admin: 0,
+            metadata: metadata.clone(),
+            deposit: 0,
+            min_renter_rating: 0,
+            requires_verification: false,
+            required_license_class: 0,
+            included_km_per_day: 0,
+            overage_fee_per_km: 0,
+        };
+
+        write_car(env, &owner, &car);
+        events::request_listing::listing_requested(env, owner, price_per_day, metadata);
+        Ok(())
     }
 
-    fn rental(
+    fn approve_listing(
         env: &Env,
-        renter: Address,
+        approver: Address,
         owner: Address,
-        total_days_to_rent: u32,
-        amount: i128,
+        commission: i128,
     ) -> Result<(), Error> {
-        renter.require_auth();
+        require_listing_approver(env, &approver)?;
 
-        if amount <= 0 {
-            return Err(Error::AmountMustBePositive);
+        let mut car = read_car(env, &owner)?;
+
+        if car.car_status != CarStatus::PendingApproval {
+            return Err(Error::ListingNotPending);
         }
 
-        if total_days_to_rent == 0 {
-            return Err(Error::RentalDurationCannotBeZero);
+        if !(0..=100).contains(&commission) {
+            return Err(Error::CommissionTooHigh);
         }
 
-        if renter == owner {
-            return Err(Error::SelfRentalNotAllowed);
+        car.car_status = CarStatus::Available;
+        car.comission_to_admin = commission;
+
+        write_car(env, &owner, &car);
+        events::approve_listing::listing_approved(env, approver, owner.clone(), commission);
+        mint_car_token(env, &owner)?;
+        Ok(())
+    }
+
+    fn reject_listing(
+        env: &Env,
+        approver: Address,
+        owner: Address,
+        reason: String,
+    ) -> Result<(), Error> {
+        require_listing_approver(env, &approver)?;
+
+        let car = read_car(env, &owner)?;
+
+        if car.car_status != CarStatus::PendingApproval {
+            return Err(Error::ListingNotPending);
         }
 
-        let mut car = read_car(env, &owner)?;
+        remove_car(env, &owner);
+        events::reject_listing::listing_rejected(env, approver, owner, reason);
+        Ok(())
+    }
 
-        if car.car_status != CarStatus::Available {
-            return Err(Error::CarAlreadyRented);
+    fn add_fleet_manager(env: &Env, manager: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        write_fleet_manager(env, &manager);
+        events::fleet_manager::fleet_manager_added(env, manager);
+        Ok(())
+    }
+
+    fn remove_fleet_manager(env: &Env, manager: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        remove_fleet_manager(env, &manager);
+        events::fleet_manager::fleet_manager_removed(env, manager);
+        Ok(())
+    }
+
+    fn get_car_status(env: &Env, owner: Address) -> Result<CarStatus, Error> {
+        get_car_status(env, &owner)
+    }
+
+    fn set_pricing_rules(env: &Env, owner: Address, rules: PricingRules) -> Result<(), Error> {
+        require_car_holder(env, &owner);
+
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
         }
 
-        // Calcular el monto total incluyendo la comisión
-        let total_to_pay = if car.comission_to_admin > 0 {
-            let commission = car
-                .comission_to_admin
-                .checked_mul(amount)
-                .ok_or(Error::MathOverFlow)?
-                .checked_div(100)
-                .ok_or(Error::MathOverFlow)?;
+        validate_pricing_rules(&rules)?;
 
-            amount.checked_add(commission).ok_or(Error::MathOverFlow)?
-        } else {
-            amount
-        };
+        write_pricing_rules(env, &owner, &rules);
+        events::pricing_rules::pricing_rules_updated(env, owner);
+        Ok(())
+    }
+
+    fn get_pricing_rules(env: &Env, owner: Address) -> Result<PricingRules, Error> {
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+
+        Ok(read_pricing_rules(env, &owner))
+    }
+
+    fn get_quote(env: &Env, owner: Address, total_days_to_rent: u32) -> Result<i128, Error> {
+        if total_days_to_rent == 0 {
+            return Err(Error::RentalDurationCannotBeZero);
+        }
+
+        let car = read_car(env, &owner)?;
+        let rules = read_pricing_rules(env, &owner);
+
+        quote_rental(env, &car, &rules, total_days_to_rent)
+    }
+
+    fn rental(
+        env: &Env,
+        renter: Address,
+        owner: Address,
+        total_days_to_rent: u32,
+        amount: i128,
+        options: RentalOptions,
+        credential: Option<Credential>,
+    ) -> Result<u64, Error> {
+        renter.require_auth();
 
-        // El arrendatario paga el monto total (alquiler + comisión)
-        token_transfer(
-            &env,
+        let rental = create_rental(
+            env,
             &renter,
-            &env.current_contract_address(),
-            &total_to_pay,
+            &owner,
+            total_days_to_rent,
+            amount,
+            &options,
+            &credential,
+            &PaymentSource::Renter,
         )?;
-        car.car_status = CarStatus::Rented;
-
-        // Calcular y procesar la comisión si está configurada
-        let commission_amount = if car.comission_to_admin > 0 {
-            // Calcular la comisión del admin (porcentaje adicional sobre el monto del alquiler)
-            let commission = car
-                .comission_to_admin
-                .checked_mul(amount)
-                .ok_or(Error::MathOverFlow)?
-                .checked_div(100)
-                .ok_or(Error::MathOverFlow)?;
 
-            // Actualizar las comisiones acumuladas del admin
-            let current_accumulated = read_accumulated_commission(&env);
-            let new_accumulated = current_accumulated
-                .checked_add(commission)
-                .ok_or(Error::MathOverFlow)?;
-            write_accumulated_commission(&env, &new_accumulated);
+        Ok(rental.id)
+    }
 
-            commission
-        } else {
-            0 // Si no hay comisión configurada
-        };
+    fn sponsored_rental(
+        env: &Env,
+        spender: Address,
+        renter: Address,
+        owner: Address,
+        total_days_to_rent: u32,
+        amount: i128,
+        options: RentalOptions,
+    ) -> Result<u64, Error> {
+        // El renter no firma: su consentimiento es la allowance que otorgó al `spender`
+        spender.require_auth();
 
-        // El owner recibe el monto completo del alquiler
-        car.available_to_withdraw = car
-            .available_to_withdraw
-            .checked_add(amount)
-            .ok_or(Error::MathOverFlow)?;
+        let rental = create_rental(
+            env,
+            &renter,
+            &owner,
+            total_days_to_rent,
+            amount,
+            &options,
+            &None,
+            &PaymentSource::Sponsor(spender),
+        )?;
+
+        Ok(rental.id)
+    }
+
+    fn rental_on_behalf(
+        env: &Env,
+        org: Address,
+        member: Address,
+        owner: Address,
+        total_days_to_rent: u32,
+        amount: i128,
+        options: RentalOptions,
+    ) -> Result<u64, Error> {
+        member.require_auth();
 
-        // Registrar el alquiler con la comisión
-        let rental = Rental {
+        // La tesorería paga a través de la allowance que otorgó al contrato, dentro del límite
+        // del miembro. Si el auto exige verificación, el miembro debe tener una atestación vigente.
+        let rental = create_rental(
+            env,
+            &member,
+            &owner,
             total_days_to_rent,
             amount,
-            commission: commission_amount,
-        };
+            &options,
+            &None,
+            &PaymentSource::Organization(org.clone()),
+        )?;
 
-        // Actualizar el balance del contrato con el monto del alquiler más la comisión
-        let mut contract_balance = read_contract_balance(&env);
-        let total_amount = amount
-            .checked_add(commission_amount)
+        let charged = rental
+            .amount
+            .checked_add(rental.commission)
+            .ok_or(Error::MathOverFlow)?
+            .checked_add(rental.deposit)
+            .ok_or(Error::MathOverFlow)?
+            .checked_add(rental.insurance_premium)
             .ok_or(Error::MathOverFlow)?;
 
-        contract_balance = contract_balance
-            .checked_add(total_amount)
-            .ok_or(Error::MathOverFlow)?;
+        events::organization::rented_on_behalf(env, org, member, rental.id, charged);
+        Ok(rental.id)
+    }
 
-        write_contract_balance(&env, &contract_balance);
-        write_car(env, &owner, &car);
-        write_rental(env, &renter, &owner, &rental);
+    fn get_rental(env: &Env, rental_id: u64) -> Result<Rental, Error> {
+        read_rental(env, rental_id)
+    }
+
+    fn get_rentals_by_renter(
+        env: &Env,
+        renter: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<Rental>, Error> {
+        get_rentals_by_renter(env, &renter, start, limit)
+    }
+
+    fn get_rentals_by_car(
+        env: &Env,
+        owner: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<Rental>, Error> {
+        get_rentals_by_car(env, &owner, start, limit)
+    }
+
+    fn create_promo_code(
+        env: &Env,
+        code_hash: BytesN<32>,
+        discount: Discount,
+        expires_at: u64,
+        max_uses: u32,
+        car: Option<Address>,
+    ) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        if has_promo_code(env, &code_hash) {
+            return Err(Error::AlreadyExists);
+        }
+
+        let promo_code = PromoCode {
+            discount,
+            expires_at,
+            max_uses,
+            uses: 0,
+            car,
+        };
+
+        validate_promo_code(env, &promo_code)?;
+
+        write_promo_code(env, &code_hash, &promo_code);
+        events::promo_code::promo_code_created(env, code_hash);
+        Ok(())
+    }
 
-        // Emitir el evento con el monto completo del alquiler
-        events::rental::rented(env, renter, owner, total_days_to_rent, amount);
+    fn remove_promo_code(env: &Env, code_hash: BytesN<32>) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        if !has_promo_code(env, &code_hash) {
+            return Err(Error::NotFound);
+        }
+
+        remove_promo_code(env, &code_hash);
+        events::promo_code::promo_code_removed(env, code_hash);
         Ok(())
     }
 
+    fn get_promo_code(env: &Env, code_hash: BytesN<32>) -> Result<PromoCode, Error> {
+        read_promo_code(env, &code_hash)
+    }
+
     fn remove_car(env: &Env, owner: Address) -> Result<(), Error> {
         let admin = read_admin(env)?;
         admin.require_auth();
 
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
+        let car = read_car(env, &owner)?;
+
+        // No se puede dar de baja un auto con un alquiler en curso
+        if car.car_status == CarStatus::Rented || read_car_active_rental(env, &owner).is_ok() {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        if car.car_status != CarStatus::Available {
+            return Err(Error::InvalidParameters);
+        }
+
+        // Las ganancias, depósitos y reclamos pendientes necesitan el auto para liquidarse
+        if car.available_to_withdraw > 0 || has_unsettled_rentals(env, &owner)? {
+            return Err(Error::InvalidParameters);
         }
+
         remove_car(env, &owner);
+        remove_pricing_rules(env, &owner);
+        burn_car_token(env, &owner)?;
         events::remove_car::car_removed(env, owner);
         Ok(())
     }
 
-    fn payout_owner(env: &Env, owner: Address, amount: i128) -> Result<(), Error> {
-        owner.require_auth();
+    fn payout_owner(env: &Env, owner: Address, amount: i128, to: Option<Address>) -> Result<(), Error> {
+        require_car_holder(env, &owner);
 
         if amount <= 0 {
             return Err(Error::AmountMustBePositive);
         }
 
-        let mut car = read_car(&env, &owner)?;
+        let mut car = read_car(env, &owner)?;
 
         if amount > car.available_to_withdraw {
             return Err(Error::InsufficientBalance);
         }
 
-        let mut contract_balance = read_contract_balance(&env);
+        let mut contract_balance = read_contract_balance(env);
 
         if amount > contract_balance {
             return Err(Error::BalanceNotAvailableForAmountRequested);
         }
 
-        token_transfer(&env, &env.current_contract_address(), &owner, &amount)?;
+        let recipient = resolve_payout_recipient(env, &owner, to)?;
+        token_transfer(env, &env.current_contract_address(), &recipient, &amount)?;
 
         car.available_to_withdraw = car
             .available_to_withdraw
@@ -220,69 +534,1314 @@ impl RentACarContractTrait for RentACarContract {
             .checked_sub(amount)
             .ok_or(Error::MathOverFlow)?;
 
-        write_car(&env, &owner, &car);
-        write_contract_balance(&env, &contract_balance);
+        write_car(env, &owner, &car);
+        write_contract_balance(env, &contract_balance);
 
         events::payout_owner::payout_owner(env, owner, amount);
         Ok(())
     }
 
-    fn return_car(env: &Env, owner: Address) -> Result<(), Error> {
-        // Solo el dueño puede devolver el auto
-        owner.require_auth();
+    fn set_auto_payout(env: &Env, owner: Address, enabled: bool) -> Result<(), Error> {
+        require_car_holder(env, &owner);
+        read_car(env, &owner)?;
 
-        let mut car = read_car(&env, &owner)?;
-
-        // Verificar que el auto está rentado
-        if car.car_status != CarStatus::Rented {
-            return Err(Error::CarNotRented);
+        if enabled {
+            write_auto_payout(env, &owner);
+        } else {
+            remove_auto_payout(env, &owner);
         }
 
-        // Cambiar el estado del auto a disponible
-        car.car_status = CarStatus::Available;
-        write_car(&env, &owner, &car);
+        events::auto_payout::auto_payout_updated(env, owner, enabled);
+        Ok(())
+    }
 
-        // Emitir evento de devolución
-        events::return_car::car_returned(env, owner);
+    fn get_auto_payout(env: &Env, owner: Address) -> bool {
+        has_auto_payout(env, &owner)
+    }
+
+    fn set_payout_destination(env: &Env, owner: Address, destination: Address) -> Result<(), Error> {
+        require_car_holder(env, &owner);
+        read_car(env, &owner)?;
+
+        // El primer destino rige de inmediato; los cambios posteriores esperan el timelock
+        let now = env.ledger().timestamp();
+        let registered = match read_payout_destination(env, &owner) {
+            Some(_) => PayoutDestination {
+                destination: effective_payout_destination(env, &owner),
+                pending: Some(destination.clone()),
+                effective_at: now.saturating_add(PAYOUT_DESTINATION_TIMELOCK),
+            },
+            None => PayoutDestination {
+                destination: destination.clone(),
+                pending: None,
+                effective_at: now,
+            },
+        };
+
+        write_payout_destination(env, &owner, &registered);
+        events::payout_destination::payout_destination_requested(
+            env,
+            owner,
+            destination,
+            registered.effective_at,
+        );
         Ok(())
     }
 
-    fn get_admin_balance(env: &Env) -> Result<i128, Error> {
-        let admin = read_admin(env)?;
-        admin.require_auth();
+    fn get_payout_destination(env: &Env, owner: Address) -> Address {
+        effective_payout_destination(env, &owner)
+    }
 
-        let balance = read_accumulated_commission(env);
-        Ok(balance)
+    fn balance(env: &Env, holder: Address) -> u32 {
+        Base::balance(env, &holder)
+    }
+
+    fn owner_of(env: &Env, token_id: u32) -> Result<Address, Error> {
+        token_holder(env, token_id)
+    }
+
+    fn get_car_token(env: &Env, owner: Address) -> Result<u32, Error> {
+        read_car_token(env, &owner).ok_or(Error::NotFound)
+    }
+
+    fn transfer(env: &Env, from: Address, to: Address, token_id: u32) -> Result<(), Error> {
+        from.require_auth();
+        transfer_car_token(env, &from, &to, token_id)
+    }
+
+    fn transfer_from(env: &Env, spender: Address, from: Address, to: Address, token_id: u32) -> Result<(), Error> {
+        spender.require_auth();
+
+        if !can_spend_car_token(env, &spender, &from, token_id) {
+            return Err(Error::Unauthorized);
+        }
+
+        transfer_car_token(env, &from, &to, token_id)
+    }
+
+    fn approve(env: &Env, approver: Address, approved: Address, token_id: u32, live_until_ledger: u32) -> Result<(), Error> {
+        approver.require_auth();
+        approve_car_token(env, &approver, &approved, token_id, live_until_ledger)
+    }
+
+    fn approve_for_all(env: &Env, owner: Address, operator: Address, live_until_ledger: u32) -> Result<(), Error> {
+        approve_car_token_operator(env, &owner, &operator, live_until_ledger)
+    }
+
+    fn get_approved(env: &Env, token_id: u32) -> Option<Address> {
+        Base::get_approved(env, token_id)
+    }
+
+    fn is_approved_for_all(env: &Env, owner: Address, operator: Address) -> bool {
+        Base::is_approved_for_all(env, &owner, &operator)
+    }
+
+    fn name(env: &Env) -> String {
+        Base::name(env)
+    }
+
+    fn symbol(env: &Env) -> String {
+        Base::symbol(env)
     }
 
+    fn token_uri(env: &Env, token_id: u32) -> Result<String, Error> {
+        let car = read_car(env, &read_token_car(env, token_id)?)?;
+        Ok(car.metadata)
+    }
 
-    fn payout_admin(env: &Env, amount: i128) -> Result<(), Error> {
+    fn register_share_token(env: &Env, owner: Address, token: Address) -> Result<(), Error> {
         let admin = read_admin(env)?;
         admin.require_auth();
 
-        if amount <= 0 {
+        read_car(env, &owner)?;
+
+        if read_share_token(env, &owner).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+
+        // El token debe haberse desplegado para este contrato y este auto, sin participaciones emitidas
+        let client = ShareTokenClient::new(env, &token);
+        if client.admin() != env.current_contract_address()
+            || client.car() != owner
+            || client.total_supply() != 0
+        {
+            return Err(Error::InvalidParameters);
+        }
+
+        write_share_token(env, &owner, &token);
+
+        events::shares::share_token_registered(env, owner, token);
+        Ok(())
+    }
+
+    fn issue_shares(env: &Env, owner: Address, supply: i128) -> Result<(), Error> {
+        let holder = require_car_holder(env, &owner);
+        read_car(env, &owner)?;
+
+        if supply <= 0 {
             return Err(Error::AmountMustBePositive);
         }
 
-        let mut contract_balance = read_contract_balance(&env);
+        if read_car_shares(env, &owner).is_some() {
+            return Err(Error::AlreadyExists);
+        }
 
-        if amount > contract_balance {
-            return Err(Error::BalanceNotAvailableForAmountRequested);
+        // Las participaciones reemplazan al reparto entre copropietarios; no pueden convivir
+        if !read_beneficiaries(env, &owner).is_empty() {
+            return Err(Error::InvalidParameters);
         }
 
-        token_transfer(&env, &env.current_contract_address(), &admin, &amount)?;
+        let token = read_share_token(env, &owner).ok_or(Error::NotFound)?;
+        let shares = CarShares {
+            token: token.clone(),
+            revenue_per_share: 0,
+            dust: 0,
+        };
+        write_car_shares(env, &owner, &shares);
 
-        let balance = read_accumulated_commission(env);
+        ShareTokenClient::new(env, &token).mint(&holder, &supply);
 
-        if amount > balance {
-            return Err(Error::InsufficientBalance);
+        events::shares::shares_issued(env, owner, holder, supply);
+        Ok(())
+    }
+
+    fn settle_share_holders(env: &Env, car: Address, holders: Vec<(Address, i128)>) -> Result<(), Error> {
+        // Solo el token del auto informa los saldos, antes de cambiarlos
+        let shares = read_car_shares(env, &car).ok_or(Error::NotFound)?;
+        shares.token.require_auth();
+
+        for (holder, balance) in holders.iter() {
+            let mut holding = read_share_holding(env, &car, &holder);
+            settle_holding(&shares, &mut holding, balance)?;
+            write_share_holding(env, &car, &holder, &holding);
         }
 
-        contract_balance = contract_balance
+        Ok(())
+    }
+
+    fn get_car_shares(env: &Env, owner: Address) -> Result<CarShares, Error> {
+        read_car_shares(env, &owner).ok_or(Error::NotFound)
+    }
+
+    fn claimable_revenue(env: &Env, owner: Address, holder: Address) -> Result<i128, Error> {
+        let shares = read_car_shares(env, &owner).ok_or(Error::NotFound)?;
+        let balance = ShareTokenClient::new(env, &shares.token).balance(&holder);
+
+        let mut holding = read_share_holding(env, &owner, &holder);
+        settle_holding(&shares, &mut holding, balance)?;
+        Ok(holding.owed)
+    }
+
+    fn claim_revenue(env: &Env, owner: Address, holder: Address) -> Result<i128, Error> {
+        holder.require_auth();
+
+        let shares = read_car_shares(env, &owner).ok_or(Error::NotFound)?;
+        let balance = ShareTokenClient::new(env, &shares.token).balance(&holder);
+
+        let mut holding = read_share_holding(env, &owner, &holder);
+        settle_holding(&shares, &mut holding, balance)?;
+
+        let amount = holding.owed;
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        let contract_balance = read_contract_balance(env);
+        if amount > contract_balance {
+            return Err(Error::BalanceNotAvailableForAmountRequested);
+        }
+
+        token_transfer(env, &env.current_contract_address(), &holder, &amount)?;
+
+        holding.owed = 0;
+        let contract_balance = contract_balance
+            .checked_sub(amount)
+            .ok_or(Error::MathOverFlow)?;
+
+        write_share_holding(env, &owner, &holder, &holding);
+        write_contract_balance(env, &contract_balance);
+
+        events::shares::revenue_claimed(env, owner, holder, amount);
+        Ok(amount)
+    }
+
+    fn set_beneficiaries(env: &Env, owner: Address, beneficiaries: Vec<Beneficiary>) -> Result<(), Error> {
+        // Con copropietarios ya registrados, cambiar el reparto requiere la firma de todos ellos;
+        // si no hay ninguno, basta con la del titular del auto
+        let current = read_beneficiaries(env, &owner);
+        if current.is_empty() {
+            require_car_holder(env, &owner);
+        } else {
+            for beneficiary in current.iter() {
+                beneficiary.address.require_auth();
+            }
+        }
+
+        read_car(env, &owner)?;
+
+        // Una lista vacía disuelve el reparto; los saldos ya acreditados siguen disponibles
+        if beneficiaries.is_empty() {
+            remove_beneficiaries(env, &owner);
+        } else {
+            if read_car_shares(env, &owner).is_some() {
+                return Err(Error::InvalidParameters);
+            }
+
+            validate_beneficiaries(&beneficiaries)?;
+            write_beneficiaries(env, &owner, &beneficiaries);
+        }
+
+        events::beneficiary::beneficiaries_updated(env, owner, beneficiaries);
+        Ok(())
+    }
+
+    fn get_beneficiaries(env: &Env, owner: Address) -> Vec<Beneficiary> {
+        read_beneficiaries(env, &owner)
+    }
+
+    fn get_beneficiary_balance(env: &Env, owner: Address, beneficiary: Address) -> i128 {
+        read_beneficiary_balance(env, &owner, &beneficiary)
+    }
+
+    fn payout_beneficiary(env: &Env, owner: Address, beneficiary: Address, amount: i128) -> Result<(), Error> {
+        beneficiary.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let balance = read_beneficiary_balance(env, &owner, &beneficiary);
+        if amount > balance {
+            return Err(Error::InsufficientBalance);
+        }
+
+        let mut contract_balance = read_contract_balance(env);
+        if amount > contract_balance {
+            return Err(Error::BalanceNotAvailableForAmountRequested);
+        }
+
+        token_transfer(env, &env.current_contract_address(), &beneficiary, &amount)?;
+
+        let balance = balance.checked_sub(amount).ok_or(Error::MathOverFlow)?;
+        contract_balance = contract_balance
+            .checked_sub(amount)
+            .ok_or(Error::MathOverFlow)?;
+
+        write_beneficiary_balance(env, &owner, &beneficiary, &balance);
+        write_contract_balance(env, &contract_balance);
+
+        events::beneficiary::beneficiary_payout(env, owner, beneficiary, amount);
+        Ok(())
+    }
+
+    fn return_car(env: &Env, owner: Address) -> Result<(), Error> {
+        // Solo el titular del auto puede devolverlo
+        require_car_holder(env, &owner);
+
+        let mut car = read_car(env, &owner)?;
+
+        // Verificar que el auto está rentado
+        if car.car_status != CarStatus::Rented {
+            return Err(Error::CarNotRented);
+        }
+
+        let mut rental = read_rental(env, read_car_active_rental(env, &owner)?)?;
+        rental.returned_ts = Some(env.ledger().timestamp());
+
+        // Cambiar el estado del auto a disponible
+        car.car_status = CarStatus::Available;
+        write_car(env, &owner, &car);
+
+        // Si el owner reclamó mientras el alquiler estaba vencido, el depósito lo liquida el árbitro
+        if rental.status != RentalStatus::Disputed && rental.status != RentalStatus::Settled {
+            transition_rental(&mut rental, RentalStatus::Returned)?;
+
+            // Sin la firma del renter, el excedente de kilometraje solo se cobra del depósito
+            charge_mileage_overage(env, &mut rental, false)?;
+            release_earnings(env, &mut rental, true)?;
+        }
+        write_rental(env, &rental);
+        remove_car_active_rental(env, &owner);
+
+        // Emitir evento de devolución
+        events::return_car::car_returned(env, owner);
+        Ok(())
+    }
+
+    fn extend_rental(env: &Env, rental_id: u64, extra_days: u32) -> Result<i128, Error> {
+        let mut rental = read_rental(env, rental_id)?;
+
+        // Como en `sponsored_rental`, el sponsor firma en lugar del renter
+        match &rental.payment {
+            PaymentSource::Sponsor(spender) => spender.require_auth(),
+            _ => rental.renter.require_auth(),
+        }
+
+        if extra_days == 0 {
+            return Err(Error::RentalDurationCannotBeZero);
+        }
+
+        if rental.status != RentalStatus::Active {
+            return Err(Error::RentalNotActive);
+        }
+
+        if is_blocked(env, &rental.renter) || is_blocked(env, &car_holder(env, &rental.owner)) {
+            return Err(Error::AddressBlocked);
+        }
+
+        // Un auto admite un solo alquiler a la vez, así que el único conflicto posible es otro alquiler
+        if read_car_active_rental(env, &rental.owner)? != rental_id {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        // Los días extra se cotizan con las reglas vigentes a partir del fin actual del alquiler y
+        // con el descuento por estadía larga de la duración total
+        let car = read_car(env, &rental.owner)?;
+        let rules = read_pricing_rules(env, &rental.owner);
+        let price = calculate_extension_price(
+            &car,
+            &rules,
+            rental.end_ts,
+            rental.total_days_to_rent,
+            extra_days,
+        )?;
+
+        let commission = car
+            .comission_to_admin
+            .checked_mul(price)
+            .ok_or(Error::MathOverFlow)?
+            .checked_div(100)
+            .ok_or(Error::MathOverFlow)?;
+
+        // Un alquiler asegurado sigue asegurado durante los días extra
+        let insurance_premium = if rental.insurance_premium > 0 {
+            let config = read_insurance_config(env)?;
+            price
+                .checked_mul(config.premium_percentage as i128)
+                .ok_or(Error::MathOverFlow)?
+                .checked_div(100)
+                .ok_or(Error::MathOverFlow)?
+        } else {
+            0
+        };
+
+        let total_to_pay = price
+            .checked_add(commission)
+            .ok_or(Error::MathOverFlow)?
+            .checked_add(insurance_premium)
+            .ok_or(Error::MathOverFlow)?;
+
+        // Los días extra se cobran al mismo origen de fondos que el alquiler original
+        collect_payment(env, &rental.renter, &rental.payment, total_to_pay)?;
+
+        rental.end_ts = rental
+            .end_ts
+            .checked_add(
+                (extra_days as u64)
+                    .checked_mul(DAY_IN_SECONDS)
+                    .ok_or(Error::MathOverFlow)?,
+            )
+            .ok_or(Error::MathOverFlow)?;
+        rental.total_days_to_rent = rental
+            .total_days_to_rent
+            .checked_add(extra_days)
+            .ok_or(Error::MathOverFlow)?;
+        rental.amount = rental.amount.checked_add(price).ok_or(Error::MathOverFlow)?;
+        rental.commission = rental
+            .commission
+            .checked_add(commission)
+            .ok_or(Error::MathOverFlow)?;
+        rental.insurance_premium = rental
+            .insurance_premium
+            .checked_add(insurance_premium)
+            .ok_or(Error::MathOverFlow)?;
+
+        if insurance_premium > 0 {
+            let pool_balance = read_insurance_pool_balance(env)
+                .checked_add(insurance_premium)
+                .ok_or(Error::MathOverFlow)?;
+            write_insurance_pool_balance(env, &pool_balance);
+        }
+
+        let accumulated_commission = read_accumulated_commission(env)
+            .checked_add(commission)
+            .ok_or(Error::MathOverFlow)?;
+
+        let contract_balance = read_contract_balance(env)
+            .checked_add(total_to_pay)
+            .ok_or(Error::MathOverFlow)?;
+
+        write_accumulated_commission(env, &accumulated_commission);
+        write_contract_balance(env, &contract_balance);
+        write_rental(env, &rental);
+
+        events::rental::rental_extended(env, rental_id, extra_days, total_to_pay);
+        Ok(total_to_pay)
+    }
+
+    fn confirm_pickup(env: &Env, rental_id: u64, report: HandoverReport) -> Result<(), Error> {
+        let mut rental = read_rental(env, rental_id)?;
+        rental.renter.require_auth();
+        require_car_holder(env, &rental.owner);
+
+        // El alquiler pasa a estar en curso cuando ambas partes firman la entrega
+        transition_rental(&mut rental, RentalStatus::Active)?;
+
+        let handover = Handover {
+            report: report.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        // Las lecturas del reporter de telemetría tienen prioridad sobre las informadas en la entrega
+        let mut readings = read_odometer(env, rental_id);
+        if readings.start.is_none() {
+            readings.start = report.odometer;
+            write_odometer(env, rental_id, &readings);
+        }
+
+        write_pickup(env, rental_id, &handover);
+        write_rental(env, &rental);
+
+        events::handover::pickup_confirmed(env, rental_id, report.condition_hash);
+        Ok(())
+    }
+
+    fn confirm_return(env: &Env, rental_id: u64, report: HandoverReport) -> Result<(), Error> {
+        let mut rental = read_rental(env, rental_id)?;
+        rental.renter.require_auth();
+        require_car_holder(env, &rental.owner);
+
+        transition_rental(&mut rental, RentalStatus::Returned)?;
+
+        let now = env.ledger().timestamp();
+        rental.returned_ts = Some(now);
+
+        // El reporter ya no registra lecturas tras la devolución, así que el reporte firmado es la
+        // lectura más reciente; sin odómetro en el reporte vale la última lectura del reporter
+        if report.odometer.is_some() {
+            let mut readings = read_odometer(env, rental_id);
+            readings.end = report.odometer;
+            write_odometer(env, rental_id, &readings);
+        }
+
+        charge_mileage_overage(env, &mut rental, true)?;
+        release_earnings(env, &mut rental, true)?;
+
+        let mut car = read_car(env, &rental.owner)?;
+        car.car_status = CarStatus::Available;
+
+        let handover = Handover {
+            report: report.clone(),
+            timestamp: now,
+        };
+
+        write_return(env, rental_id, &handover);
+        write_car(env, &rental.owner, &car);
+        write_rental(env, &rental);
+        remove_car_active_rental(env, &rental.owner);
+
+        events::handover::return_confirmed(env, rental_id, report.condition_hash);
+        Ok(())
+    }
+
+    fn get_pickup(env: &Env, rental_id: u64) -> Result<Handover, Error> {
+        read_pickup(env, rental_id)
+    }
+
+    fn get_return(env: &Env, rental_id: u64) -> Result<Handover, Error> {
+        read_return(env, rental_id)
+    }
+
+    fn set_mileage_policy(
+        env: &Env,
+        owner: Address,
+        included_km_per_day: u32,
+        overage_fee_per_km: i128,
+    ) -> Result<(), Error> {
+        require_car_holder(env, &owner);
+
+        if overage_fee_per_km < 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        let mut car = read_car(env, &owner)?;
+        car.included_km_per_day = included_km_per_day;
+        car.overage_fee_per_km = overage_fee_per_km;
+        write_car(env, &owner, &car);
+
+        events::mileage::mileage_policy_updated(env, owner, included_km_per_day, overage_fee_per_km);
+        Ok(())
+    }
+
+    fn set_telematics_reporter(env: &Env, owner: Address, reporter: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        read_car(env, &owner)?;
+
+        write_telematics_reporter(env, &owner, &reporter);
+        events::telematics::telematics_reporter_set(env, owner, reporter);
+        Ok(())
+    }
+
+    fn remove_telematics_reporter(env: &Env, owner: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        remove_telematics_reporter(env, &owner);
+        events::telematics::telematics_reporter_removed(env, owner);
+        Ok(())
+    }
+
+    fn report_odometer(env: &Env, rental_id: u64, odometer: u64) -> Result<(), Error> {
+        let rental = read_rental(env, rental_id)?;
+        let reporter = read_telematics_reporter(env, &rental.owner)?;
+        reporter.require_auth();
+
+        record_reporter_odometer(env, &rental, odometer)?;
+        events::mileage::odometer_reported(env, rental_id, reporter, odometer);
+        Ok(())
+    }
+
+    fn push_telematics(env: &Env, owner: Address, status: TelematicsStatus) -> Result<(), Error> {
+        let reporter = read_telematics_reporter(env, &owner)?;
+        reporter.require_auth();
+
+        let mut car = read_car(env, &owner)?;
+
+        // La lectura del odómetro alimenta el cálculo de kilometraje del alquiler en curso; un
+        // alquiler en disputa o liquidado ya no la registra, pero el snapshot se guarda igual
+        if let Ok(rental_id) = read_car_active_rental(env, &owner) {
+            let rental = read_rental(env, rental_id)?;
+            if matches!(
+                rental.status,
+                RentalStatus::Reserved | RentalStatus::Active | RentalStatus::Overdue
+            ) {
+                record_reporter_odometer(env, &rental, status.odometer)?;
+            }
+        }
+
+        // Una falla saca de circulación a un auto disponible hasta que el owner la resuelva
+        if !status.fault_codes.is_empty() && car.car_status == CarStatus::Available {
+            car.car_status = CarStatus::Maintenance;
+            write_car(env, &owner, &car);
+            events::telematics::car_sent_to_maintenance(env, owner.clone(), status.fault_codes.clone());
+        }
+
+        let snapshot = TelematicsSnapshot {
+            status: status.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        write_telematics_snapshot(env, &owner, &snapshot);
+        events::telematics::telematics_updated(env, owner, status);
+        Ok(())
+    }
+
+    fn get_telematics(env: &Env, owner: Address) -> Result<TelematicsSnapshot, Error> {
+        read_telematics_snapshot(env, &owner)
+    }
+
+    fn clear_maintenance(env: &Env, owner: Address) -> Result<(), Error> {
+        require_car_holder(env, &owner);
+
+        let mut car = read_car(env, &owner)?;
+
+        if car.car_status != CarStatus::Maintenance {
+            return Err(Error::InvalidParameters);
+        }
+
+        car.car_status = CarStatus::Available;
+        write_car(env, &owner, &car);
+
+        events::telematics::maintenance_cleared(env, owner);
+        Ok(())
+    }
+
+    fn get_odometer_readings(env: &Env, rental_id: u64) -> OdometerReadings {
+        read_odometer(env, rental_id)
+    }
+
+    fn release_earnings(env: &Env, rental_id: u64) -> Result<i128, Error> {
+        let mut rental = read_rental(env, rental_id)?;
+        require_car_holder(env, &rental.owner);
+
+        if rental.status != RentalStatus::Active && rental.status != RentalStatus::Overdue {
+            return Err(Error::RentalNotActive);
+        }
+
+        let released = release_earnings(env, &mut rental, false)?;
+        write_rental(env, &rental);
+        Ok(released)
+    }
+
+    fn cancel_rental(env: &Env, rental_id: u64) -> Result<(), Error> {
+        let mut rental = read_rental(env, rental_id)?;
+        rental.renter.require_auth();
+
+        // Solo el renter que cancela paga los días que el auto estuvo reservado
+        let refunded = cancel_reservation(env, &mut rental, true)?;
+
+        events::cancel_rental::rental_cancelled(env, rental.id, rental.renter, refunded);
+        Ok(())
+    }
+
+    fn expire_reservation(env: &Env, rental_id: u64) -> Result<(), Error> {
+        let mut rental = read_rental(env, rental_id)?;
+
+        if rental.status != RentalStatus::Reserved {
+            return Err(Error::InvalidRentalTransition);
+        }
+
+        // Pasado el plazo de retiro sin confirmar la entrega, cualquiera puede liberar el auto.
+        // La entrega requiere ambas firmas, así que puede faltar la del owner: el renter recupera todo.
+        if env.ledger().timestamp() <= rental.start_ts.saturating_add(PICKUP_WINDOW) {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        let refunded = cancel_reservation(env, &mut rental, false)?;
+
+        events::cancel_rental::reservation_expired(env, rental.id, refunded);
+        Ok(())
+    }
+
+    fn set_car_deposit(env: &Env, owner: Address, deposit: i128) -> Result<(), Error> {
+        require_car_holder(env, &owner);
+
+        if deposit < 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let mut car = read_car(env, &owner)?;
+        car.deposit = deposit;
+        write_car(env, &owner, &car);
+
+        events::deposit::deposit_updated(env, owner, deposit);
+        Ok(())
+    }
+
+    fn release_deposit(env: &Env, rental_id: u64) -> Result<(), Error> {
+        let mut rental = read_rental(env, rental_id)?;
+
+        // Un alquiler en disputa solo se liquida a través del árbitro. Uno vencido sin devolver
+        // también se libera si el owner no reclama a tiempo, aunque nunca devuelva el auto.
+        let window_start = match rental.status {
+            RentalStatus::Returned => rental.returned_ts.unwrap_or(rental.end_ts),
+            RentalStatus::Overdue => rental.overdue_ts.unwrap_or(rental.end_ts),
+            _ => return Err(Error::InvalidRentalTransition),
+        };
+
+        // El depósito solo se libera cuando vence el plazo para reclamar daños
+        if env.ledger().timestamp() <= window_start.saturating_add(CLAIM_WINDOW) {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        transition_rental(&mut rental, RentalStatus::Settled)?;
+        settle_deposit(env, &rental, 0)?;
+        write_rental(env, &rental);
+
+        events::deposit::deposit_released(env, rental.id, rental.renter, rental.deposit);
+        Ok(())
+    }
+
+    fn set_overdue_policy(env: &Env, policy: OverduePolicy) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        if policy.keeper_reward_percentage > 100 {
+            return Err(Error::InvalidParameters);
+        }
+
+        write_overdue_policy(env, &policy);
+        events::overdue::overdue_policy_updated(env, policy);
+        Ok(())
+    }
+
+    fn get_overdue_policy(env: &Env) -> OverduePolicy {
+        read_overdue_policy(env)
+    }
+
+    fn settle_overdue(env: &Env, keeper: Address, rental_id: u64) -> Result<(), Error> {
+        keeper.require_auth();
+
+        let mut rental = read_rental(env, rental_id)?;
+        let policy = read_overdue_policy(env);
+
+        // Quien paga el atraso no puede cobrar la recompensa por señalarlo
+        if keeper == rental.renter || keeper == rental.payer {
+            return Err(Error::Unauthorized);
+        }
+
+        if env.ledger().timestamp() <= rental.end_ts.saturating_add(policy.grace_period) {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        if rental.status != RentalStatus::Active {
+            return Err(Error::RentalNotActive);
+        }
+        transition_rental(&mut rental, RentalStatus::Overdue)?;
+
+        // El período pagado terminó, así que el owner cobra el alquiler completo
+        release_earnings(env, &mut rental, true)?;
+
+        // La penalidad se fija acá. El resto del depósito queda retenido hasta la devolución o un
+        // reclamo; si el owner no reclama dentro del plazo, `release_deposit` se lo devuelve al renter.
+        let (penalty, reward) = charge_late_penalty(env, &mut rental, &keeper, &policy)?;
+        rental.overdue_ts = Some(env.ledger().timestamp());
+
+        write_rental(env, &rental);
+
+        events::overdue::overdue_settled(env, rental_id, keeper, penalty, reward);
+        Ok(())
+    }
+
+    fn set_arbiter(env: &Env, arbiter: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        write_arbiter(env, &arbiter);
+        events::arbiter::arbiter_updated(env, arbiter);
+        Ok(())
+    }
+
+    fn file_claim(
+        env: &Env,
+        rental_id: u64,
+        amount: i128,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        let mut rental = read_rental(env, rental_id)?;
+        require_car_holder(env, &rental.owner);
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        // Sobre un alquiler vencido sin devolver se puede reclamar en cualquier momento
+        let now = env.ledger().timestamp();
+        if let Some(returned_ts) = rental.returned_ts {
+            if now > returned_ts.saturating_add(CLAIM_WINDOW) {
+                return Err(Error::DeadlinePassed);
+            }
+        }
+
+        // Con entrega firmada, solo se reclama si el estado del auto cambió según la devolución
+        // firmada por ambas partes. Un auto devuelto solo por el owner (`return_car`) no tiene
+        // informe de devolución y no admite reclamos; uno vencido sin devolver sí.
+        if let Ok(pickup) = read_pickup(env, rental_id) {
+            match read_return(env, rental_id) {
+                Ok(ret) if pickup.report.condition_hash == ret.report.condition_hash => {
+                    return Err(Error::ConditionUnchanged);
+                }
+                Err(_) if rental.returned_ts.is_some() => return Err(Error::RentalNotReturned),
+                _ => {}
+            }
+        }
+
+        // El depósito del renter queda congelado mientras el reclamo esté abierto
+        transition_rental(&mut rental, RentalStatus::Disputed)?;
+
+        let claim = Claim {
+            amount,
+            owner_evidence: evidence_hash.clone(),
+            renter_evidence: None,
+            filed_ts: now,
+            deadline: now.saturating_add(CLAIM_RESOLUTION_PERIOD),
+        };
+
+        write_claim(env, rental_id, &claim);
+        write_rental(env, &rental);
+
+        events::claim::claim_filed(env, rental_id, rental.owner, amount, evidence_hash);
+        Ok(())
+    }
+
+    fn respond_claim(env: &Env, rental_id: u64, evidence_hash: BytesN<32>) -> Result<(), Error> {
+        let rental = read_rental(env, rental_id)?;
+        rental.renter.require_auth();
+
+        let mut claim = read_claim(env, rental_id)?;
+
+        if rental.status != RentalStatus::Disputed {
+            return Err(Error::InvalidRentalTransition);
+        }
+
+        if env.ledger().timestamp() > claim.deadline {
+            return Err(Error::DeadlinePassed);
+        }
+
+        claim.renter_evidence = Some(evidence_hash.clone());
+        write_claim(env, rental_id, &claim);
+
+        events::claim::claim_responded(env, rental_id, rental.renter, evidence_hash);
+        Ok(())
+    }
+
+    fn resolve_claim(env: &Env, rental_id: u64, owner_share: i128) -> Result<(), Error> {
+        let arbiter = read_arbiter(env)?;
+        arbiter.require_auth();
+
+        let mut rental = read_rental(env, rental_id)?;
+        let claim = read_claim(env, rental_id)?;
+
+        if env.ledger().timestamp() > claim.deadline {
+            return Err(Error::DeadlinePassed);
+        }
+
+        if owner_share > claim.amount {
+            return Err(Error::InvalidParameters);
+        }
+
+        transition_rental(&mut rental, RentalStatus::Settled)?;
+
+        // El fondo de seguros cubre primero, hasta su límite; el resto sale del depósito
+        let deposit_share = pay_claim_from_insurance(env, &rental, owner_share)?;
+        settle_deposit(env, &rental, deposit_share)?;
+        write_rental(env, &rental);
+
+        events::claim::claim_resolved(env, rental_id, arbiter, owner_share);
+        Ok(())
+    }
+
+    fn expire_claim(env: &Env, rental_id: u64) -> Result<(), Error> {
+        let mut rental = read_rental(env, rental_id)?;
+        let claim = read_claim(env, rental_id)?;
+
+        if env.ledger().timestamp() <= claim.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        // Un reclamo sin resolver a tiempo se cierra a favor del renter
+        transition_rental(&mut rental, RentalStatus::Settled)?;
+        settle_deposit(env, &rental, 0)?;
+        write_rental(env, &rental);
+
+        events::claim::claim_expired(env, rental_id);
+        Ok(())
+    }
+
+    fn get_claim(env: &Env, rental_id: u64) -> Result<Claim, Error> {
+        read_claim(env, rental_id)
+    }
+
+    fn rate_rental(
+        env: &Env,
+        rental_id: u64,
+        rater: Address,
+        score: u32,
+        review_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        rater.require_auth();
+
+        if !(1..=5).contains(&score) {
+            return Err(Error::InvalidParameters);
+        }
+
+        let rental = read_rental(env, rental_id)?;
+
+        // Cada parte califica a la otra: el renter al titular del auto y el titular al renter
+        let holder = car_holder(env, &rental.owner);
+        let rated = if rater == rental.renter {
+            holder
+        } else if rater == holder {
+            rental.renter.clone()
+        } else {
+            return Err(Error::Unauthorized);
+        };
+
+        if rental.returned_ts.is_none() {
+            return Err(Error::RentalNotReturned);
+        }
+
+        if has_rated(env, rental_id, &rater) {
+            return Err(Error::AlreadyExists);
+        }
+
+        let mut reputation = read_reputation(env, &rated);
+        reputation.count = reputation.count.checked_add(1).ok_or(Error::MathOverFlow)?;
+        reputation.sum = reputation.sum.checked_add(score).ok_or(Error::MathOverFlow)?;
+
+        write_reputation(env, &rated, &reputation);
+        write_rated(env, rental_id, &rater);
+
+        events::rate_rental::rated(env, rental_id, rater, rated, score, review_hash);
+        Ok(())
+    }
+
+    fn get_reputation(env: &Env, address: Address) -> Reputation {
+        read_reputation(env, &address)
+    }
+
+    fn set_min_renter_rating(env: &Env, owner: Address, min_rating: u32) -> Result<(), Error> {
+        require_car_holder(env, &owner);
+
+        if min_rating > 5 {
+            return Err(Error::InvalidParameters);
+        }
+
+        let mut car = read_car(env, &owner)?;
+        car.min_renter_rating = min_rating;
+        write_car(env, &owner, &car);
+
+        Ok(())
+    }
+
+    fn add_verifier(env: &Env, verifier: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        write_verifier(env, &verifier);
+        events::verification::verifier_added(env, verifier);
+        Ok(())
+    }
+
+    fn remove_verifier(env: &Env, verifier: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        remove_verifier(env, &verifier);
+        events::verification::verifier_removed(env, verifier);
+        Ok(())
+    }
+
+    fn register_attestation(
+        env: &Env,
+        verifier: Address,
+        renter: Address,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        verifier.require_auth();
+
+        if !is_verifier(env, &verifier) {
+            return Err(Error::Unauthorized);
+        }
+
+        if expires_at <= env.ledger().timestamp() {
+            return Err(Error::InvalidParameters);
+        }
+
+        let attestation = Attestation {
+            verifier: verifier.clone(),
+            expires_at,
+        };
+
+        write_attestation(env, &renter, &attestation);
+        events::verification::attestation_registered(env, verifier, renter, expires_at);
+        Ok(())
+    }
+
+    fn revoke_attestation(env: &Env, verifier: Address, renter: Address) -> Result<(), Error> {
+        verifier.require_auth();
+
+        if !is_verifier(env, &verifier) {
+            return Err(Error::Unauthorized);
+        }
+
+        read_attestation(env, &renter)?;
+
+        remove_attestation(env, &renter);
+        events::verification::attestation_revoked(env, verifier, renter);
+        Ok(())
+    }
+
+    fn add_verifier_key(env: &Env, public_key: BytesN<32>) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        write_verifier_key(env, &public_key);
+        events::verification::verifier_key_added(env, public_key);
+        Ok(())
+    }
+
+    fn remove_verifier_key(env: &Env, public_key: BytesN<32>) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        remove_verifier_key(env, &public_key);
+        events::verification::verifier_key_removed(env, public_key);
+        Ok(())
+    }
+
+    fn get_attestation(env: &Env, renter: Address) -> Result<Attestation, Error> {
+        read_attestation(env, &renter)
+    }
+
+    fn set_requires_verification(env: &Env, owner: Address, required: bool) -> Result<(), Error> {
+        require_car_holder(env, &owner);
+
+        let mut car = read_car(env, &owner)?;
+        car.requires_verification = required;
+        write_car(env, &owner, &car);
+
+        Ok(())
+    }
+
+    fn set_required_license_class(env: &Env, owner: Address, license_class: u32) -> Result<(), Error> {
+        require_car_holder(env, &owner);
+
+        let mut car = read_car(env, &owner)?;
+        car.required_license_class = license_class;
+        write_car(env, &owner, &car);
+
+        Ok(())
+    }
+
+    fn block_address(env: &Env, address: Address, reason: String) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        // Los fondos ya ganados se pueden seguir retirando; solo se impiden nuevos alquileres
+        write_blocked(env, &address, &reason);
+        events::blocklist::address_blocked(env, address, reason);
+        Ok(())
+    }
+
+    fn unblock_address(env: &Env, address: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        if !is_blocked(env, &address) {
+            return Err(Error::NotFound);
+        }
+
+        remove_blocked(env, &address);
+        events::blocklist::address_unblocked(env, address);
+        Ok(())
+    }
+
+    fn is_blocked(env: &Env, address: Address) -> bool {
+        is_blocked(env, &address)
+    }
+
+    fn create_organization(env: &Env, org: Address, treasury: Address) -> Result<(), Error> {
+        org.require_auth();
+        // La tesorería consiente que sus fondos paguen los alquileres de esta empresa
+        treasury.require_auth();
+
+        if has_organization(env, &org) {
+            return Err(Error::AlreadyExists);
+        }
+
+        // Una tesorería respalda a una sola empresa: su allowance no se comparte con otras
+        if read_treasury_organization(env, &treasury).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+
+        write_organization(env, &org, &Organization { treasury: treasury.clone() });
+        write_treasury_organization(env, &treasury, &org);
+        events::organization::organization_created(env, org, treasury);
+        Ok(())
+    }
+
+    fn get_organization(env: &Env, org: Address) -> Result<Organization, Error> {
+        read_organization(env, &org)
+    }
+
+    fn set_org_member(
+        env: &Env,
+        org: Address,
+        member: Address,
+        limit: i128,
+        period: SpendingPeriod,
+    ) -> Result<(), Error> {
+        org.require_auth();
+        read_organization(env, &org)?;
+
+        if limit <= 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        // Cambiar el límite conserva lo gastado en el período en curso
+        let member_limit = match read_org_member(env, &org, &member) {
+            Ok(current) if current.period == period => MemberLimit { limit, ..current },
+            _ => MemberLimit {
+                limit,
+                period,
+                spent: 0,
+                period_start: 0,
+            },
+        };
+
+        write_org_member(env, &org, &member, &member_limit);
+        events::organization::org_member_set(env, org, member, limit, period);
+        Ok(())
+    }
+
+    fn remove_org_member(env: &Env, org: Address, member: Address) -> Result<(), Error> {
+        org.require_auth();
+        read_org_member(env, &org, &member)?;
+
+        remove_org_member(env, &org, &member);
+        events::organization::org_member_removed(env, org, member);
+        Ok(())
+    }
+
+    fn get_org_member(env: &Env, org: Address, member: Address) -> Result<MemberLimit, Error> {
+        read_org_member(env, &org, &member)
+    }
+
+    fn add_driver(
+        env: &Env,
+        rental_id: u64,
+        driver: Address,
+        credential: Option<Credential>,
+    ) -> Result<(), Error> {
+        let rental = read_rental(env, rental_id)?;
+        rental.renter.require_auth();
+        driver.require_auth();
+
+        if rental.status != RentalStatus::Active {
+            return Err(Error::RentalNotActive);
+        }
+
+        if driver == rental.renter || driver == car_holder(env, &rental.owner) {
+            return Err(Error::InvalidParameters);
+        }
+
+        if is_blocked(env, &driver) {
+            return Err(Error::AddressBlocked);
+        }
+
+        let mut drivers = read_drivers(env, rental_id);
+
+        if drivers.contains(&driver) {
+            return Err(Error::AlreadyExists);
+        }
+
+        if drivers.len() >= MAX_ADDITIONAL_DRIVERS {
+            return Err(Error::DriverLimitReached);
+        }
+
+        // Los conductores adicionales cumplen las mismas reglas de verificación que el renter
+        let car = read_car(env, &rental.owner)?;
+        require_verified_renter(env, &driver, &car, &credential)?;
+
+        drivers.push_back(driver.clone());
+        write_drivers(env, rental_id, &drivers);
+
+        events::driver::driver_added(env, rental_id, driver);
+        Ok(())
+    }
+
+    fn remove_driver(env: &Env, rental_id: u64, driver: Address) -> Result<(), Error> {
+        let rental = read_rental(env, rental_id)?;
+        rental.renter.require_auth();
+
+        let mut drivers = read_drivers(env, rental_id);
+        let index = drivers.first_index_of(&driver).ok_or(Error::NotFound)?;
+
+        drivers.remove(index);
+        write_drivers(env, rental_id, &drivers);
+
+        events::driver::driver_removed(env, rental_id, driver);
+        Ok(())
+    }
+
+    fn get_drivers(env: &Env, rental_id: u64) -> Result<Vec<Address>, Error> {
+        let rental = read_rental(env, rental_id)?;
+
+        // El renter encabeza la lista de conductores autorizados
+        let mut drivers = Vec::from_array(env, [rental.renter]);
+        drivers.append(&read_drivers(env, rental_id));
+
+        Ok(drivers)
+    }
+
+    fn set_insurance_config(
+        env: &Env,
+        premium_percentage: u32,
+        coverage_limit: i128,
+    ) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        if premium_percentage > 100 || coverage_limit < 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        let config = InsuranceConfig {
+            premium_percentage,
+            coverage_limit,
+        };
+
+        write_insurance_config(env, &config);
+        events::insurance::insurance_config_updated(env, premium_percentage, coverage_limit);
+        Ok(())
+    }
+
+    fn get_insurance_config(env: &Env) -> Result<InsuranceConfig, Error> {
+        read_insurance_config(env)
+    }
+
+    fn get_insurance_pool_balance(env: &Env) -> Result<i128, Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        Ok(read_insurance_pool_balance(env))
+    }
+
+    fn top_up_insurance_pool(env: &Env, amount: i128) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        token_transfer(env, &admin, &env.current_contract_address(), &amount)?;
+
+        let pool_balance = read_insurance_pool_balance(env)
+            .checked_add(amount)
+            .ok_or(Error::MathOverFlow)?;
+        let contract_balance = read_contract_balance(env)
+            .checked_add(amount)
+            .ok_or(Error::MathOverFlow)?;
+
+        write_insurance_pool_balance(env, &pool_balance);
+        write_contract_balance(env, &contract_balance);
+
+        events::insurance::insurance_pool_topped_up(env, admin, amount);
+        Ok(())
+    }
+
+    fn get_admin_balance(env: &Env) -> Result<i128, Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        let balance = read_accumulated_commission(env);
+        Ok(balance)
+    }
+
+
+    fn payout_admin(env: &Env, amount: i128, to: Option<Address>) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        // El admin solo retira su comisión acumulada, nunca los fondos retenidos de renters y owners
+        let commission = read_accumulated_commission(env);
+        if amount > commission {
+            return Err(Error::InsufficientBalance);
+        }
+
+        let contract_balance = read_contract_balance(env);
+        if amount > contract_balance {
+            return Err(Error::BalanceNotAvailableForAmountRequested);
+        }
+
+        let recipient = to.unwrap_or(admin.clone());
+        token_transfer(env, &env.current_contract_address(), &recipient, &amount)?;
+
+        let commission = commission.checked_sub(amount).ok_or(Error::MathOverFlow)?;
+        let contract_balance = contract_balance
             .checked_sub(amount)
             .ok_or(Error::MathOverFlow)?;
 
-        write_contract_balance(&env, &contract_balance);
+        write_accumulated_commission(env, &commission);
+        write_contract_balance(env, &contract_balance);
 
         events::payout_admin::payout_admin(env, admin, amount);
         Ok(())
@@ -0,0 +1,10 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn listing_approved(env: &Env, approver: Address, owner: Address, commission: i128) {
+    let topics = (Symbol::new(env, "listing_approved"), owner.clone());
+
+    env.events().publish(
+        topics,
+        (approver, commission)
+    );
+}
\ No newline at end of file
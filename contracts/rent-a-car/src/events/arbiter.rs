@@ -0,0 +1,6 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn arbiter_updated(env: &Env, arbiter: Address) {
+    let topics = (Symbol::new(env, "arbiter_updated"), arbiter);
+    env.events().publish(topics, ());
+}
\ No newline at end of file
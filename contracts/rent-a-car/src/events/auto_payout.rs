@@ -0,0 +1,6 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn auto_payout_updated(env: &Env, owner: Address, enabled: bool) {
+    let topics = (Symbol::new(env, "auto_payout_updated"), owner);
+    env.events().publish(topics, enabled);
+}
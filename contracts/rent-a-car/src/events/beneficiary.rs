@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::storage::structs::beneficiary::Beneficiary;
+
+pub(crate) fn beneficiaries_updated(env: &Env, owner: Address, beneficiaries: Vec<Beneficiary>) {
+    let topics = (Symbol::new(env, "beneficiaries_updated"), owner);
+    env.events().publish(topics, beneficiaries);
+}
+
+pub(crate) fn beneficiary_payout(env: &Env, owner: Address, beneficiary: Address, amount: i128) {
+    let topics = (Symbol::new(env, "beneficiary_payout"), owner, beneficiary);
+    env.events().publish(topics, amount);
+}
@@ -0,0 +1,11 @@
+use soroban_sdk::{Address, Env, String, Symbol};
+
+pub(crate) fn address_blocked(env: &Env, address: Address, reason: String) {
+    let topics = (Symbol::new(env, "address_blocked"), address);
+    env.events().publish(topics, reason);
+}
+
+pub(crate) fn address_unblocked(env: &Env, address: Address) {
+    let topics = (Symbol::new(env, "address_unblocked"), address);
+    env.events().publish(topics, ());
+}
\ No newline at end of file
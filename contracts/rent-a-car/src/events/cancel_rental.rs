@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn rental_cancelled(env: &Env, rental_id: u64, renter: Address, refunded: i128) {
+    let topics = (Symbol::new(env, "rental_cancelled"), rental_id, renter);
+
+    env.events().publish(
+        topics,
+        refunded
+    );
+}
+
+pub(crate) fn reservation_expired(env: &Env, rental_id: u64, refunded: i128) {
+    let topics = (Symbol::new(env, "reservation_expired"), rental_id);
+    env.events().publish(topics, refunded);
+}
\ No newline at end of file
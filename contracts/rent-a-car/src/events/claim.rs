@@ -0,0 +1,21 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub(crate) fn claim_filed(env: &Env, rental_id: u64, owner: Address, amount: i128, evidence_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "claim_filed"), rental_id, owner);
+    env.events().publish(topics, (amount, evidence_hash));
+}
+
+pub(crate) fn claim_responded(env: &Env, rental_id: u64, renter: Address, evidence_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "claim_responded"), rental_id, renter);
+    env.events().publish(topics, evidence_hash);
+}
+
+pub(crate) fn claim_resolved(env: &Env, rental_id: u64, arbiter: Address, owner_share: i128) {
+    let topics = (Symbol::new(env, "claim_resolved"), rental_id, arbiter);
+    env.events().publish(topics, owner_share);
+}
+
+pub(crate) fn claim_expired(env: &Env, rental_id: u64) {
+    let topics = (Symbol::new(env, "claim_expired"), rental_id);
+    env.events().publish(topics, ());
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn deposit_updated(env: &Env, owner: Address, deposit: i128) {
+    let topics = (Symbol::new(env, "deposit_updated"), owner);
+    env.events().publish(topics, deposit);
+}
+
+pub(crate) fn deposit_released(env: &Env, rental_id: u64, renter: Address, amount: i128) {
+    let topics = (Symbol::new(env, "deposit_released"), rental_id, renter);
+    env.events().publish(topics, amount);
+}
\ No newline at end of file
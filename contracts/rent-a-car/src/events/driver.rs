@@ -0,0 +1,11 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn driver_added(env: &Env, rental_id: u64, driver: Address) {
+    let topics = (Symbol::new(env, "driver_added"), rental_id);
+    env.events().publish(topics, driver);
+}
+
+pub(crate) fn driver_removed(env: &Env, rental_id: u64, driver: Address) {
+    let topics = (Symbol::new(env, "driver_removed"), rental_id);
+    env.events().publish(topics, driver);
+}
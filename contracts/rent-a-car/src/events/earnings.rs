@@ -0,0 +1,6 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn earnings_released(env: &Env, rental_id: u64, owner: Address, amount: i128) {
+    let topics = (Symbol::new(env, "earnings_released"), rental_id, owner);
+    env.events().publish(topics, amount);
+}
@@ -0,0 +1,11 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn fleet_manager_added(env: &Env, manager: Address) {
+    let topics = (Symbol::new(env, "fleet_manager_added"), manager);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn fleet_manager_removed(env: &Env, manager: Address) {
+    let topics = (Symbol::new(env, "fleet_manager_removed"), manager);
+    env.events().publish(topics, ());
+}
\ No newline at end of file
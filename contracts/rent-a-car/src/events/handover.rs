@@ -0,0 +1,11 @@
+use soroban_sdk::{BytesN, Env, Symbol};
+
+pub(crate) fn pickup_confirmed(env: &Env, rental_id: u64, condition_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "pickup_confirmed"), rental_id);
+    env.events().publish(topics, condition_hash);
+}
+
+pub(crate) fn return_confirmed(env: &Env, rental_id: u64, condition_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "return_confirmed"), rental_id);
+    env.events().publish(topics, condition_hash);
+}
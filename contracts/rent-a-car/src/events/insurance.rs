@@ -0,0 +1,11 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn insurance_config_updated(env: &Env, premium_percentage: u32, coverage_limit: i128) {
+    let topics = (Symbol::new(env, "insurance_config_updated"),);
+    env.events().publish(topics, (premium_percentage, coverage_limit));
+}
+
+pub(crate) fn insurance_pool_topped_up(env: &Env, admin: Address, amount: i128) {
+    let topics = (Symbol::new(env, "insurance_pool_topped_up"), admin);
+    env.events().publish(topics, amount);
+}
\ No newline at end of file
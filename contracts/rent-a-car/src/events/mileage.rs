@@ -0,0 +1,16 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn mileage_policy_updated(env: &Env, owner: Address, included_km_per_day: u32, overage_fee_per_km: i128) {
+    let topics = (Symbol::new(env, "mileage_policy_updated"), owner);
+    env.events().publish(topics, (included_km_per_day, overage_fee_per_km));
+}
+
+pub(crate) fn odometer_reported(env: &Env, rental_id: u64, reporter: Address, odometer: u64) {
+    let topics = (Symbol::new(env, "odometer_reported"), rental_id, reporter);
+    env.events().publish(topics, odometer);
+}
+
+pub(crate) fn mileage_overage_charged(env: &Env, rental_id: u64, extra_km: u64, charged: i128) {
+    let topics = (Symbol::new(env, "mileage_overage_charged"), rental_id);
+    env.events().publish(topics, (extra_km, charged));
+}
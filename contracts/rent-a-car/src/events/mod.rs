@@ -4,4 +4,29 @@ pub mod payout_owner;
 pub mod rental;
 pub mod add_car;
 pub mod return_car;
-pub mod payout_admin;
\ No newline at end of file
+pub mod payout_admin;
+pub mod request_listing;
+pub mod approve_listing;
+pub mod reject_listing;
+pub mod fleet_manager;
+pub mod pricing_rules;
+pub mod promo_code;
+pub mod cancel_rental;
+pub mod claim;
+pub mod deposit;
+pub mod arbiter;
+pub mod insurance;
+pub mod rate_rental;
+pub mod verification;
+pub mod blocklist;
+pub mod driver;
+pub mod organization;
+pub mod overdue;
+pub mod handover;
+pub mod mileage;
+pub mod telematics;
+pub mod earnings;
+pub mod auto_payout;
+pub mod payout_destination;
+pub mod beneficiary;
+pub mod shares;
@@ -0,0 +1,23 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::structs::organization::SpendingPeriod;
+
+pub(crate) fn organization_created(env: &Env, org: Address, treasury: Address) {
+    let topics = (Symbol::new(env, "organization_created"), org);
+    env.events().publish(topics, treasury);
+}
+
+pub(crate) fn org_member_set(env: &Env, org: Address, member: Address, limit: i128, period: SpendingPeriod) {
+    let topics = (Symbol::new(env, "org_member_set"), org, member);
+    env.events().publish(topics, (limit, period));
+}
+
+pub(crate) fn org_member_removed(env: &Env, org: Address, member: Address) {
+    let topics = (Symbol::new(env, "org_member_removed"), org, member);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn rented_on_behalf(env: &Env, org: Address, member: Address, rental_id: u64, charged: i128) {
+    let topics = (Symbol::new(env, "rented_on_behalf"), org, member);
+    env.events().publish(topics, (rental_id, charged));
+}
@@ -0,0 +1,18 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::structs::overdue_policy::OverduePolicy;
+
+pub(crate) fn overdue_policy_updated(env: &Env, policy: OverduePolicy) {
+    let topics = (Symbol::new(env, "overdue_policy_updated"),);
+    env.events().publish(topics, policy);
+}
+
+pub(crate) fn late_penalty_charged(env: &Env, rental_id: u64, amount: i128) {
+    let topics = (Symbol::new(env, "late_penalty_charged"), rental_id);
+    env.events().publish(topics, amount);
+}
+
+pub(crate) fn overdue_settled(env: &Env, rental_id: u64, keeper: Address, penalty: i128, reward: i128) {
+    let topics = (Symbol::new(env, "overdue_settled"), rental_id, keeper);
+    env.events().publish(topics, (penalty, reward));
+}
@@ -0,0 +1,6 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn payout_destination_requested(env: &Env, owner: Address, destination: Address, effective_at: u64) {
+    let topics = (Symbol::new(env, "payout_destination_requested"), owner);
+    env.events().publish(topics, (destination, effective_at));
+}
@@ -0,0 +1,6 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn pricing_rules_updated(env: &Env, owner: Address) {
+    let topics = (Symbol::new(env, "pricing_rules_updated"), owner);
+    env.events().publish(topics, ());
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use soroban_sdk::{BytesN, Address, Env, Symbol};
+
+pub(crate) fn promo_code_created(env: &Env, code_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "promo_code_created"), code_hash);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn promo_code_removed(env: &Env, code_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "promo_code_removed"), code_hash);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn promo_code_redeemed(env: &Env, code_hash: BytesN<32>, renter: Address, discount: i128) {
+    let topics = (Symbol::new(env, "promo_code_redeemed"), code_hash, renter);
+    env.events().publish(topics, discount);
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub(crate) fn rated(
+    env: &Env,
+    rental_id: u64,
+    rater: Address,
+    rated: Address,
+    score: u32,
+    review_hash: BytesN<32>,
+) {
+    let topics = (Symbol::new(env, "rated"), rental_id, rater);
+
+    env.events().publish(
+        topics,
+        (rated, score, review_hash)
+    );
+}
\ No newline at end of file
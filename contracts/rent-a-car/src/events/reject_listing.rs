@@ -0,0 +1,10 @@
+use soroban_sdk::{Address, Env, String, Symbol};
+
+pub(crate) fn listing_rejected(env: &Env, approver: Address, owner: Address, reason: String) {
+    let topics = (Symbol::new(env, "listing_rejected"), owner.clone());
+
+    env.events().publish(
+        topics,
+        (approver, reason)
+    );
+}
\ No newline at end of file
@@ -13,4 +13,9 @@ pub(crate) fn rented(
         topics,
         (total_days, amount)
     );
+}
+
+pub(crate) fn rental_extended(env: &Env, rental_id: u64, extra_days: u32, charged: i128) {
+    let topics = (Symbol::new(env, "rental_extended"), rental_id);
+    env.events().publish(topics, (extra_days, charged));
 }
\ No newline at end of file
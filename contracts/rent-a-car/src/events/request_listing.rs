@@ -0,0 +1,10 @@
+use soroban_sdk::{Address, Env, String, Symbol};
+
+pub(crate) fn listing_requested(env: &Env, owner: Address, price_per_day: i128, metadata: String) {
+    let topics = (Symbol::new(env, "listing_requested"), owner.clone());
+
+    env.events().publish(
+        topics,
+        (price_per_day, metadata)
+    );
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn shares_issued(env: &Env, car: Address, holder: Address, supply: i128) {
+    let topics = (Symbol::new(env, "shares_issued"), car, holder);
+    env.events().publish(topics, supply);
+}
+
+pub(crate) fn share_token_registered(env: &Env, car: Address, token: Address) {
+    let topics = (Symbol::new(env, "share_token_registered"), car);
+    env.events().publish(topics, token);
+}
+
+pub(crate) fn revenue_claimed(env: &Env, car: Address, holder: Address, amount: i128) {
+    let topics = (Symbol::new(env, "revenue_claimed"), car, holder);
+    env.events().publish(topics, amount);
+}
@@ -0,0 +1,28 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::storage::structs::telematics::TelematicsStatus;
+
+pub(crate) fn telematics_reporter_set(env: &Env, owner: Address, reporter: Address) {
+    let topics = (Symbol::new(env, "telematics_reporter_set"), owner);
+    env.events().publish(topics, reporter);
+}
+
+pub(crate) fn telematics_reporter_removed(env: &Env, owner: Address) {
+    let topics = (Symbol::new(env, "telematics_reporter_removed"), owner);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn telematics_updated(env: &Env, owner: Address, status: TelematicsStatus) {
+    let topics = (Symbol::new(env, "telematics_updated"), owner);
+    env.events().publish(topics, status);
+}
+
+pub(crate) fn car_sent_to_maintenance(env: &Env, owner: Address, fault_codes: Vec<u32>) {
+    let topics = (Symbol::new(env, "car_sent_to_maintenance"), owner);
+    env.events().publish(topics, fault_codes);
+}
+
+pub(crate) fn maintenance_cleared(env: &Env, owner: Address) {
+    let topics = (Symbol::new(env, "maintenance_cleared"), owner);
+    env.events().publish(topics, ());
+}
@@ -0,0 +1,31 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub(crate) fn verifier_added(env: &Env, verifier: Address) {
+    let topics = (Symbol::new(env, "verifier_added"), verifier);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn verifier_removed(env: &Env, verifier: Address) {
+    let topics = (Symbol::new(env, "verifier_removed"), verifier);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn attestation_registered(env: &Env, verifier: Address, renter: Address, expires_at: u64) {
+    let topics = (Symbol::new(env, "attestation_registered"), verifier, renter);
+    env.events().publish(topics, expires_at);
+}
+
+pub(crate) fn attestation_revoked(env: &Env, verifier: Address, renter: Address) {
+    let topics = (Symbol::new(env, "attestation_revoked"), verifier, renter);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn verifier_key_added(env: &Env, public_key: BytesN<32>) {
+    let topics = (Symbol::new(env, "verifier_key_added"), public_key);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn verifier_key_removed(env: &Env, public_key: BytesN<32>) {
+    let topics = (Symbol::new(env, "verifier_key_removed"), public_key);
+    env.events().publish(topics, ());
+}
\ No newline at end of file
@@ -1,15 +1,121 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
-use crate::storage::types::{car_status::CarStatus, error::Error};
+use crate::storage::{
+    structs::{
+        attestation::Attestation, beneficiary::Beneficiary, claim::Claim, credential::Credential, insurance::InsuranceConfig,
+        handover::{Handover, HandoverReport},
+        organization::{MemberLimit, Organization, SpendingPeriod}, odometer::OdometerReadings, overdue_policy::OverduePolicy,
+        pricing_rules::PricingRules, promo_code::{Discount, PromoCode}, rental::Rental,
+        rental_options::RentalOptions, reputation::Reputation, shares::CarShares,
+        telematics::{TelematicsSnapshot, TelematicsStatus},
+    },
+    types::{car_status::CarStatus, error::Error},
+};
 
 pub trait RentACarContractTrait {
     fn __constructor(env: &Env, admin: Address, token: Address) -> Result<(), Error>;
     fn add_car(env: &Env, owner: Address, price_per_day: i128, commission: i128) -> Result<(), Error>;
+    fn request_listing(env: &Env, owner: Address, metadata: String, price_per_day: i128) -> Result<(), Error>;
+    fn approve_listing(env: &Env, approver: Address, owner: Address, commission: i128) -> Result<(), Error>;
+    fn reject_listing(env: &Env, approver: Address, owner: Address, reason: String) -> Result<(), Error>;
+    fn add_fleet_manager(env: &Env, manager: Address) -> Result<(), Error>;
+    fn remove_fleet_manager(env: &Env, manager: Address) -> Result<(), Error>;
     fn get_car_status(env: &Env, owner: Address) -> Result<CarStatus, Error>;
-    fn rental(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128) -> Result<(), Error>;
+    fn set_pricing_rules(env: &Env, owner: Address, rules: PricingRules) -> Result<(), Error>;
+    fn get_pricing_rules(env: &Env, owner: Address) -> Result<PricingRules, Error>;
+    fn get_quote(env: &Env, owner: Address, total_days_to_rent: u32) -> Result<i128, Error>;
+    fn rental(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128, options: RentalOptions, credential: Option<Credential>) -> Result<u64, Error>;
+    fn sponsored_rental(env: &Env, spender: Address, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128, options: RentalOptions) -> Result<u64, Error>;
+    fn rental_on_behalf(env: &Env, org: Address, member: Address, owner: Address, total_days_to_rent: u32, amount: i128, options: RentalOptions) -> Result<u64, Error>;
+    fn get_rental(env: &Env, rental_id: u64) -> Result<Rental, Error>;
+    fn get_rentals_by_renter(env: &Env, renter: Address, start: u32, limit: u32) -> Result<Vec<Rental>, Error>;
+    fn get_rentals_by_car(env: &Env, owner: Address, start: u32, limit: u32) -> Result<Vec<Rental>, Error>;
+    fn create_promo_code(env: &Env, code_hash: BytesN<32>, discount: Discount, expires_at: u64, max_uses: u32, car: Option<Address>) -> Result<(), Error>;
+    fn remove_promo_code(env: &Env, code_hash: BytesN<32>) -> Result<(), Error>;
+    fn get_promo_code(env: &Env, code_hash: BytesN<32>) -> Result<PromoCode, Error>;
     fn remove_car(env: &Env, owner: Address) -> Result<(), Error>;
-    fn payout_owner(env: &Env, owner: Address, amount: i128) -> Result<(), Error>;
+    fn payout_owner(env: &Env, owner: Address, amount: i128, to: Option<Address>) -> Result<(), Error>;
+    fn set_auto_payout(env: &Env, owner: Address, enabled: bool) -> Result<(), Error>;
+    fn get_auto_payout(env: &Env, owner: Address) -> bool;
+    fn set_payout_destination(env: &Env, owner: Address, destination: Address) -> Result<(), Error>;
+    fn get_payout_destination(env: &Env, owner: Address) -> Address;
+    fn balance(env: &Env, holder: Address) -> u32;
+    fn owner_of(env: &Env, token_id: u32) -> Result<Address, Error>;
+    fn get_car_token(env: &Env, owner: Address) -> Result<u32, Error>;
+    fn transfer(env: &Env, from: Address, to: Address, token_id: u32) -> Result<(), Error>;
+    fn transfer_from(env: &Env, spender: Address, from: Address, to: Address, token_id: u32) -> Result<(), Error>;
+    fn approve(env: &Env, approver: Address, approved: Address, token_id: u32, live_until_ledger: u32) -> Result<(), Error>;
+    fn approve_for_all(env: &Env, owner: Address, operator: Address, live_until_ledger: u32) -> Result<(), Error>;
+    fn get_approved(env: &Env, token_id: u32) -> Option<Address>;
+    fn is_approved_for_all(env: &Env, owner: Address, operator: Address) -> bool;
+    fn name(env: &Env) -> String;
+    fn symbol(env: &Env) -> String;
+    fn token_uri(env: &Env, token_id: u32) -> Result<String, Error>;
+    fn register_share_token(env: &Env, owner: Address, token: Address) -> Result<(), Error>;
+    fn issue_shares(env: &Env, owner: Address, supply: i128) -> Result<(), Error>;
+    fn settle_share_holders(env: &Env, car: Address, holders: Vec<(Address, i128)>) -> Result<(), Error>;
+    fn get_car_shares(env: &Env, owner: Address) -> Result<CarShares, Error>;
+    fn claimable_revenue(env: &Env, owner: Address, holder: Address) -> Result<i128, Error>;
+    fn claim_revenue(env: &Env, owner: Address, holder: Address) -> Result<i128, Error>;
+    fn set_beneficiaries(env: &Env, owner: Address, beneficiaries: Vec<Beneficiary>) -> Result<(), Error>;
+    fn get_beneficiaries(env: &Env, owner: Address) -> Vec<Beneficiary>;
+    fn get_beneficiary_balance(env: &Env, owner: Address, beneficiary: Address) -> i128;
+    fn payout_beneficiary(env: &Env, owner: Address, beneficiary: Address, amount: i128) -> Result<(), Error>;
     fn return_car(env: &Env, owner: Address) -> Result<(), Error>;
+    fn extend_rental(env: &Env, rental_id: u64, extra_days: u32) -> Result<i128, Error>;
+    fn confirm_pickup(env: &Env, rental_id: u64, report: HandoverReport) -> Result<(), Error>;
+    fn confirm_return(env: &Env, rental_id: u64, report: HandoverReport) -> Result<(), Error>;
+    fn get_pickup(env: &Env, rental_id: u64) -> Result<Handover, Error>;
+    fn get_return(env: &Env, rental_id: u64) -> Result<Handover, Error>;
+    fn set_mileage_policy(env: &Env, owner: Address, included_km_per_day: u32, overage_fee_per_km: i128) -> Result<(), Error>;
+    fn set_telematics_reporter(env: &Env, owner: Address, reporter: Address) -> Result<(), Error>;
+    fn remove_telematics_reporter(env: &Env, owner: Address) -> Result<(), Error>;
+    fn report_odometer(env: &Env, rental_id: u64, odometer: u64) -> Result<(), Error>;
+    fn push_telematics(env: &Env, owner: Address, status: TelematicsStatus) -> Result<(), Error>;
+    fn get_telematics(env: &Env, owner: Address) -> Result<TelematicsSnapshot, Error>;
+    fn clear_maintenance(env: &Env, owner: Address) -> Result<(), Error>;
+    fn get_odometer_readings(env: &Env, rental_id: u64) -> OdometerReadings;
+    fn release_earnings(env: &Env, rental_id: u64) -> Result<i128, Error>;
+    fn cancel_rental(env: &Env, rental_id: u64) -> Result<(), Error>;
+    fn expire_reservation(env: &Env, rental_id: u64) -> Result<(), Error>;
+    fn set_car_deposit(env: &Env, owner: Address, deposit: i128) -> Result<(), Error>;
+    fn release_deposit(env: &Env, rental_id: u64) -> Result<(), Error>;
+    fn set_overdue_policy(env: &Env, policy: OverduePolicy) -> Result<(), Error>;
+    fn get_overdue_policy(env: &Env) -> OverduePolicy;
+    fn settle_overdue(env: &Env, keeper: Address, rental_id: u64) -> Result<(), Error>;
+    fn set_arbiter(env: &Env, arbiter: Address) -> Result<(), Error>;
+    fn file_claim(env: &Env, rental_id: u64, amount: i128, evidence_hash: BytesN<32>) -> Result<(), Error>;
+    fn respond_claim(env: &Env, rental_id: u64, evidence_hash: BytesN<32>) -> Result<(), Error>;
+    fn resolve_claim(env: &Env, rental_id: u64, owner_share: i128) -> Result<(), Error>;
+    fn expire_claim(env: &Env, rental_id: u64) -> Result<(), Error>;
+    fn get_claim(env: &Env, rental_id: u64) -> Result<Claim, Error>;
+    fn rate_rental(env: &Env, rental_id: u64, rater: Address, score: u32, review_hash: BytesN<32>) -> Result<(), Error>;
+    fn get_reputation(env: &Env, address: Address) -> Reputation;
+    fn set_min_renter_rating(env: &Env, owner: Address, min_rating: u32) -> Result<(), Error>;
+    fn add_verifier(env: &Env, verifier: Address) -> Result<(), Error>;
+    fn remove_verifier(env: &Env, verifier: Address) -> Result<(), Error>;
+    fn register_attestation(env: &Env, verifier: Address, renter: Address, expires_at: u64) -> Result<(), Error>;
+    fn revoke_attestation(env: &Env, verifier: Address, renter: Address) -> Result<(), Error>;
+    fn add_verifier_key(env: &Env, public_key: BytesN<32>) -> Result<(), Error>;
+    fn remove_verifier_key(env: &Env, public_key: BytesN<32>) -> Result<(), Error>;
+    fn get_attestation(env: &Env, renter: Address) -> Result<Attestation, Error>;
+    fn set_requires_verification(env: &Env, owner: Address, required: bool) -> Result<(), Error>;
+    fn set_required_license_class(env: &Env, owner: Address, license_class: u32) -> Result<(), Error>;
+    fn block_address(env: &Env, address: Address, reason: String) -> Result<(), Error>;
+    fn unblock_address(env: &Env, address: Address) -> Result<(), Error>;
+    fn is_blocked(env: &Env, address: Address) -> bool;
+    fn create_organization(env: &Env, org: Address, treasury: Address) -> Result<(), Error>;
+    fn get_organization(env: &Env, org: Address) -> Result<Organization, Error>;
+    fn set_org_member(env: &Env, org: Address, member: Address, limit: i128, period: SpendingPeriod) -> Result<(), Error>;
+    fn remove_org_member(env: &Env, org: Address, member: Address) -> Result<(), Error>;
+    fn get_org_member(env: &Env, org: Address, member: Address) -> Result<MemberLimit, Error>;
+    fn add_driver(env: &Env, rental_id: u64, driver: Address, credential: Option<Credential>) -> Result<(), Error>;
+    fn remove_driver(env: &Env, rental_id: u64, driver: Address) -> Result<(), Error>;
+    fn get_drivers(env: &Env, rental_id: u64) -> Result<Vec<Address>, Error>;
+    fn set_insurance_config(env: &Env, premium_percentage: u32, coverage_limit: i128) -> Result<(), Error>;
+    fn get_insurance_config(env: &Env) -> Result<InsuranceConfig, Error>;
+    fn get_insurance_pool_balance(env: &Env) -> Result<i128, Error>;
+    fn top_up_insurance_pool(env: &Env, amount: i128) -> Result<(), Error>;
     fn get_admin_balance(env: &Env) -> Result<i128, Error>;
-    fn payout_admin(env: &Env, amount: i128) -> Result<(), Error>;
+    fn payout_admin(env: &Env, amount: i128, to: Option<Address>) -> Result<(), Error>;
 }
\ No newline at end of file
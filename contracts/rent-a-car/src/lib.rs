@@ -6,5 +6,6 @@ mod tests;
 mod storage;
 mod methods;
 mod events;
+mod constants;
 
 pub use contract::RentACarContract;
\ No newline at end of file
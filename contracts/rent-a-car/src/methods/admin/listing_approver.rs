@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{admin::read_admin, fleet_manager::is_fleet_manager, types::error::Error};
+
+/// Verifica que quien aprueba o rechaza una publicación sea el admin o un gestor de flota
+pub fn require_listing_approver(env: &Env, approver: &Address) -> Result<(), Error> {
+    let admin = read_admin(env)?;
+
+    if *approver != admin && !is_fleet_manager(env, approver) {
+        return Err(Error::Unauthorized);
+    }
+
+    approver.require_auth();
+    Ok(())
+}
\ No newline at end of file
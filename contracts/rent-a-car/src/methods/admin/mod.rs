@@ -0,0 +1 @@
+pub mod listing_approver;
\ No newline at end of file
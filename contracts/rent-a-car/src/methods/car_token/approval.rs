@@ -0,0 +1,61 @@
+use soroban_sdk::{Address, Env};
+use stellar_tokens::non_fungible::Base;
+
+use crate::{methods::car_token::ownership::token_holder, storage::types::error::Error};
+
+/// Un `live_until_ledger` en 0 revoca la aprobación; cualquier otro valor debe caer dentro del
+/// TTL que admite la red
+fn validate_live_until_ledger(env: &Env, live_until_ledger: u32) -> Result<(), Error> {
+    if live_until_ledger == 0 {
+        return Ok(());
+    }
+
+    if live_until_ledger < env.ledger().sequence()
+        || live_until_ledger > env.ledger().max_live_until_ledger()
+    {
+        return Err(Error::InvalidParameters);
+    }
+
+    Ok(())
+}
+
+/// Aprueba a `approved` para transferir un NFT. Puede hacerlo su titular o un operador del titular.
+pub fn approve_car_token(
+    env: &Env,
+    approver: &Address,
+    approved: &Address,
+    token_id: u32,
+    live_until_ledger: u32,
+) -> Result<(), Error> {
+    let holder = token_holder(env, token_id)?;
+    if *approver != holder && !Base::is_approved_for_all(env, &holder, approver) {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_live_until_ledger(env, live_until_ledger)?;
+
+    Base::approve_for_owner(env, &holder, approver, approved, token_id, live_until_ledger);
+    Ok(())
+}
+
+/// Aprueba a `operator` para transferir todos los NFTs presentes y futuros de `holder`. La
+/// firma de `holder` la exige `Base::approve_for_all`.
+pub fn approve_car_token_operator(
+    env: &Env,
+    holder: &Address,
+    operator: &Address,
+    live_until_ledger: u32,
+) -> Result<(), Error> {
+    validate_live_until_ledger(env, live_until_ledger)?;
+
+    Base::approve_for_all(env, holder, operator, live_until_ledger);
+    Ok(())
+}
+
+/// `spender` puede mover el NFT de `holder` si es el titular, la dirección aprobada para ese NFT
+/// o un operador del titular
+pub fn can_spend_car_token(env: &Env, spender: &Address, holder: &Address, token_id: u32) -> bool {
+    spender == holder
+        || Base::get_approved(env, token_id).is_some_and(|approved| approved == *spender)
+        || Base::is_approved_for_all(env, holder, spender)
+}
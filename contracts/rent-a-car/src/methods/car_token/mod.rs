@@ -0,0 +1,3 @@
+pub mod approval;
+pub mod ownership;
+pub mod transfer;
@@ -0,0 +1,65 @@
+use soroban_sdk::{Address, Env};
+use stellar_tokens::non_fungible::{burnable::emit_burn, emit_transfer, Base};
+
+use crate::storage::{
+    car_token::{
+        next_token_id, read_car_token, read_token_car, remove_car_token, remove_token_car, write_car_token,
+        write_token_car,
+    },
+    types::error::Error,
+};
+
+/// Titular actual de un NFT. El registro de titulares y saldos lo lleva `stellar_tokens`; el
+/// contrato solo conserva la relación entre cada NFT y su auto.
+pub fn token_holder(env: &Env, token_id: u32) -> Result<Address, Error> {
+    read_token_car(env, token_id)?;
+    Ok(Base::owner_of(env, token_id))
+}
+
+/// Titular actual de un auto: quien tiene su NFT o, si el auto no fue tokenizado, la dirección con la que se publicó
+pub fn car_holder(env: &Env, car: &Address) -> Address {
+    match read_car_token(env, car) {
+        Some(token_id) => token_holder(env, token_id).unwrap_or(car.clone()),
+        None => car.clone(),
+    }
+}
+
+/// Exige la firma del titular actual del auto y lo devuelve
+pub fn require_car_holder(env: &Env, car: &Address) -> Address {
+    let holder = car_holder(env, car);
+    holder.require_auth();
+    holder
+}
+
+/// Emite el NFT de un auto recién publicado a nombre de su owner
+pub fn mint_car_token(env: &Env, car: &Address) -> Result<u32, Error> {
+    let token_id = next_token_id(env);
+
+    write_car_token(env, car, token_id);
+    write_token_car(env, token_id, car);
+    Base::mint(env, car, token_id);
+
+    Ok(token_id)
+}
+
+/// Quema el NFT de un auto dado de baja, si lo tenía
+pub fn burn_car_token(env: &Env, car: &Address) -> Result<(), Error> {
+    let Some(token_id) = read_car_token(env, car) else {
+        return Ok(());
+    };
+
+    let holder = token_holder(env, token_id)?;
+    Base::update(env, Some(&holder), None, token_id);
+
+    remove_token_car(env, token_id);
+    remove_car_token(env, car);
+
+    emit_burn(env, &holder, token_id);
+    Ok(())
+}
+
+/// Cambia el titular del NFT. La aprobación del NFT no pasa al nuevo titular.
+pub fn move_car_token(env: &Env, from: &Address, to: &Address, token_id: u32) {
+    Base::update(env, Some(from), Some(to), token_id);
+    emit_transfer(env, from, to, token_id);
+}
@@ -0,0 +1,72 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events,
+    methods::{
+        car_token::ownership::{move_car_token, token_holder},
+        payout::destination::effective_payout_destination,
+        token::token::token_transfer,
+    },
+    storage::{
+        auto_payout::remove_auto_payout,
+        beneficiary::read_beneficiaries,
+        car::{read_car, write_car},
+        car_token::read_token_car,
+        contract_balance::{read_contract_balance, write_contract_balance},
+        payout_destination::write_payout_destination,
+        structs::payout_destination::PayoutDestination,
+        types::{car_status::CarStatus, error::Error},
+    },
+};
+
+/// Vende el auto representado por un NFT: le paga al titular anterior lo ya ganado, mueve el NFT
+/// y reinicia la configuración de pagos. Quien llama ya verificó la autorización.
+pub fn transfer_car_token(env: &Env, from: &Address, to: &Address, token_id: u32) -> Result<(), Error> {
+    if token_holder(env, token_id)? != *from {
+        return Err(Error::Unauthorized);
+    }
+
+    let car_key = read_token_car(env, token_id)?;
+    let mut car = read_car(env, &car_key)?;
+
+    // No se puede vender un auto con un alquiler en curso
+    if car.car_status == CarStatus::Rented {
+        return Err(Error::CarAlreadyRented);
+    }
+
+    // Con copropietarios, el reparto debe disolverse antes de vender el auto
+    if !read_beneficiaries(env, &car_key).is_empty() {
+        return Err(Error::InvalidParameters);
+    }
+
+    // Lo ya ganado es del titular anterior: se le paga a su destino antes de vender el auto.
+    // Con el comprador solo viajan las ganancias futuras.
+    let accrued = car.available_to_withdraw;
+    if accrued > 0 {
+        let contract_balance = read_contract_balance(env)
+            .checked_sub(accrued)
+            .ok_or(Error::MathOverFlow)?;
+
+        let destination = effective_payout_destination(env, &car_key);
+        token_transfer(env, &env.current_contract_address(), &destination, &accrued)?;
+
+        car.available_to_withdraw = 0;
+        write_car(env, &car_key, &car);
+        write_contract_balance(env, &contract_balance);
+
+        events::payout_owner::payout_owner(env, car_key.clone(), accrued);
+    }
+
+    move_car_token(env, from, to, token_id);
+
+    // La configuración de pagos del titular anterior no se traslada al comprador. Sus pagos van
+    // a su propia dirección y cualquier otro destino queda sujeto al timelock.
+    let destination = PayoutDestination {
+        destination: to.clone(),
+        pending: None,
+        effective_at: env.ledger().timestamp(),
+    };
+    write_payout_destination(env, &car_key, &destination);
+    remove_auto_payout(env, &car_key);
+    Ok(())
+}
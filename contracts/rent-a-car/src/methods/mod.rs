@@ -1,2 +1,9 @@
 pub mod token;
-pub mod public;
\ No newline at end of file
+pub mod public;
+pub mod admin;
+pub mod pricing;
+pub mod rental;
+pub mod verification;
+pub mod organization;
+pub mod payout;pub mod car_token;
+pub mod shares;
@@ -0,0 +1 @@
+pub mod spending;
\ No newline at end of file
@@ -0,0 +1,40 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    constants::{DAY_IN_SECONDS, MONTH_IN_SECONDS},
+    storage::{
+        organization::{read_org_member, write_org_member},
+        structs::organization::SpendingPeriod,
+        types::error::Error,
+    },
+};
+
+/// Registra un gasto del miembro contra su límite, reiniciando el acumulado al empezar un nuevo período
+pub fn charge_member(env: &Env, org: &Address, member: &Address, amount: i128) -> Result<(), Error> {
+    let mut member_limit = read_org_member(env, org, member).map_err(|_| Error::Unauthorized)?;
+
+    let period_length = match member_limit.period {
+        SpendingPeriod::Daily => DAY_IN_SECONDS,
+        SpendingPeriod::Monthly => MONTH_IN_SECONDS,
+    };
+
+    let now = env.ledger().timestamp();
+    let period_start = now - now % period_length;
+
+    if member_limit.period_start != period_start {
+        member_limit.period_start = period_start;
+        member_limit.spent = 0;
+    }
+
+    member_limit.spent = member_limit
+        .spent
+        .checked_add(amount)
+        .ok_or(Error::MathOverFlow)?;
+
+    if member_limit.spent > member_limit.limit {
+        return Err(Error::SpendingLimitExceeded);
+    }
+
+    write_org_member(env, org, member, &member_limit);
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    events,
+    methods::{payout::destination::effective_payout_destination, token::token::try_token_transfer},
+    storage::{
+        auto_payout::has_auto_payout,
+        car::{read_car, write_car},
+        contract_balance::{read_contract_balance, write_contract_balance},
+        types::error::Error,
+    },
+};
+
+/// Si el owner activó el pago automático, transfiere `amount` de su saldo retirable a su destino de pagos.
+/// Si la transferencia falla, el monto queda acumulado para un `payout_owner` posterior.
+pub fn try_auto_payout(env: &Env, owner: &Address, amount: i128) -> Result<bool, Error> {
+    if !has_auto_payout(env, owner) || amount <= 0 {
+        return Ok(false);
+    }
+
+    let destination = effective_payout_destination(env, owner);
+
+    if !try_token_transfer(env, &env.current_contract_address(), &destination, &amount)? {
+        return Ok(false);
+    }
+
+    let mut car = read_car(env, owner)?;
+    car.available_to_withdraw = car
+        .available_to_withdraw
+        .checked_sub(amount)
+        .ok_or(Error::MathOverFlow)?;
+
+    let contract_balance = read_contract_balance(env)
+        .checked_sub(amount)
+        .ok_or(Error::MathOverFlow)?;
+
+    write_car(env, owner, &car);
+    write_contract_balance(env, &contract_balance);
+
+    events::payout_owner::payout_owner(env, owner.clone(), amount);
+    Ok(true)
+}
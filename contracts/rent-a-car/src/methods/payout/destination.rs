@@ -0,0 +1,30 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    methods::car_token::ownership::car_holder,
+    storage::{payout_destination::read_payout_destination, types::error::Error},
+};
+
+/// Destino vigente de los pagos de un auto: el registrado (una vez cumplido el timelock) o el titular de su NFT
+pub fn effective_payout_destination(env: &Env, owner: &Address) -> Address {
+    match read_payout_destination(env, owner) {
+        Some(registered) => match registered.pending {
+            Some(pending) if env.ledger().timestamp() >= registered.effective_at => pending,
+            _ => registered.destination,
+        },
+        None => car_holder(env, owner),
+    }
+}
+
+/// Con un destino registrado, los pagos solo pueden ir a ese destino
+pub fn resolve_payout_recipient(env: &Env, owner: &Address, to: Option<Address>) -> Result<Address, Error> {
+    let destination = effective_payout_destination(env, owner);
+
+    match to {
+        Some(to) if read_payout_destination(env, owner).is_some() && to != destination => {
+            Err(Error::Unauthorized)
+        }
+        Some(to) => Ok(to),
+        None => Ok(destination),
+    }
+}
@@ -0,0 +1,3 @@
+pub mod auto_payout;
+pub mod destination;
+pub mod revenue_split;
@@ -0,0 +1,76 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    constants::TOTAL_SHARE_BPS,
+    storage::{
+        beneficiary::{read_beneficiaries, read_beneficiary_balance, write_beneficiary_balance},
+        structs::beneficiary::Beneficiary,
+        types::error::Error,
+    },
+};
+
+/// Las participaciones deben ser positivas, sin direcciones repetidas y sumar exactamente 10.000
+pub fn validate_beneficiaries(beneficiaries: &Vec<Beneficiary>) -> Result<(), Error> {
+    let mut total: u32 = 0;
+
+    for (index, beneficiary) in beneficiaries.iter().enumerate() {
+        if beneficiary.share_bps == 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        if beneficiaries
+            .iter()
+            .skip(index + 1)
+            .any(|other| other.address == beneficiary.address)
+        {
+            return Err(Error::InvalidParameters);
+        }
+
+        total = total
+            .checked_add(beneficiary.share_bps)
+            .ok_or(Error::MathOverFlow)?;
+    }
+
+    if total != TOTAL_SHARE_BPS {
+        return Err(Error::InvalidParameters);
+    }
+
+    Ok(())
+}
+
+/// Reparte `amount` entre los beneficiarios del auto. El resto del redondeo va al primero.
+/// Devuelve `false` si el auto no tiene beneficiarios configurados.
+pub fn split_revenue(env: &Env, owner: &Address, amount: i128) -> Result<bool, Error> {
+    let beneficiaries = read_beneficiaries(env, owner);
+    if beneficiaries.is_empty() {
+        return Ok(false);
+    }
+
+    let mut remainder = amount;
+    for beneficiary in beneficiaries.iter() {
+        let share = amount
+            .checked_mul(beneficiary.share_bps as i128)
+            .ok_or(Error::MathOverFlow)?
+            .checked_div(TOTAL_SHARE_BPS as i128)
+            .ok_or(Error::MathOverFlow)?;
+
+        credit_beneficiary(env, owner, &beneficiary.address, share)?;
+        remainder = remainder.checked_sub(share).ok_or(Error::MathOverFlow)?;
+    }
+
+    if remainder > 0 {
+        let first = beneficiaries.get_unchecked(0);
+        credit_beneficiary(env, owner, &first.address, remainder)?;
+    }
+
+    Ok(true)
+}
+
+fn credit_beneficiary(env: &Env, owner: &Address, beneficiary: &Address, amount: i128) -> Result<(), Error> {
+    let balance = read_beneficiary_balance(env, owner, beneficiary)
+        .checked_add(amount)
+        .ok_or(Error::MathOverFlow)?;
+
+    write_beneficiary_balance(env, owner, beneficiary, &balance);
+    Ok(())
+}
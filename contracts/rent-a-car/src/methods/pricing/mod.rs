@@ -0,0 +1,2 @@
+pub mod quote;
+pub mod promo_code;
\ No newline at end of file
@@ -0,0 +1,70 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+use crate::{
+    events,
+    storage::{
+        promo_code::{read_promo_code, write_promo_code},
+        structs::promo_code::{Discount, PromoCode},
+        types::error::Error,
+    },
+};
+
+/// Valida los parámetros de un código antes de registrarlo
+pub fn validate_promo_code(env: &Env, promo_code: &PromoCode) -> Result<(), Error> {
+    let valid_discount = match promo_code.discount {
+        Discount::Percentage(percentage) => percentage > 0 && percentage <= 100,
+        Discount::Fixed(amount) => amount > 0,
+    };
+
+    if !valid_discount
+        || promo_code.max_uses == 0
+        || promo_code.expires_at <= env.ledger().timestamp()
+    {
+        return Err(Error::InvalidParameters);
+    }
+
+    Ok(())
+}
+
+/// Canjea el código para el auto indicado y devuelve el precio con el descuento aplicado
+pub fn redeem_promo_code(
+    env: &Env,
+    code: &Bytes,
+    renter: &Address,
+    owner: &Address,
+    price: i128,
+) -> Result<i128, Error> {
+    let code_hash: BytesN<32> = env.crypto().sha256(code).into();
+    let mut promo_code = read_promo_code(env, &code_hash)?;
+
+    if env.ledger().timestamp() >= promo_code.expires_at {
+        return Err(Error::PromoCodeExpired);
+    }
+
+    if promo_code.uses >= promo_code.max_uses {
+        return Err(Error::PromoCodeExhausted);
+    }
+
+    if let Some(car) = &promo_code.car {
+        if car != owner {
+            return Err(Error::PromoCodeNotApplicable);
+        }
+    }
+
+    let discount = match promo_code.discount {
+        Discount::Percentage(percentage) => price
+            .checked_mul(percentage as i128)
+            .ok_or(Error::MathOverFlow)?
+            .checked_div(100)
+            .ok_or(Error::MathOverFlow)?,
+        // El descuento fijo nunca deja el precio por debajo de cero
+        Discount::Fixed(amount) => amount.min(price),
+    };
+
+    promo_code.uses = promo_code.uses.checked_add(1).ok_or(Error::MathOverFlow)?;
+    write_promo_code(env, &code_hash, &promo_code);
+
+    events::promo_code::promo_code_redeemed(env, code_hash, renter.clone(), discount);
+
+    price.checked_sub(discount).ok_or(Error::MathOverFlow)
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+use soroban_sdk::Env;
+
+use crate::{
+    constants::DAY_IN_SECONDS,
+    storage::{
+        structs::{car::Car, pricing_rules::PricingRules},
+        types::error::Error,
+    },
+};
+
+/// El 1 de enero de 1970 fue jueves: con domingo = 0, el día 0 corresponde al 4
+fn is_weekend(timestamp: u64) -> bool {
+    let weekday = (timestamp / DAY_IN_SECONDS + 4) % 7;
+    weekday == 0 || weekday == 6
+}
+
+fn apply_percentage(value: i128, percentage: u32) -> Result<i128, Error> {
+    value
+        .checked_mul(percentage as i128)
+        .ok_or(Error::MathOverFlow)?
+        .checked_div(100)
+        .ok_or(Error::MathOverFlow)
+}
+
+/// Cotiza `days` días a partir de `start_ts` aplicando el descuento que corresponde a una estadía
+/// de `stay_days` días en total
+fn price_days(
+    car: &Car,
+    rules: &PricingRules,
+    start_ts: u64,
+    days: u32,
+    stay_days: u32,
+) -> Result<i128, Error> {
+    let mut total: i128 = 0;
+
+    for day in 0..days as u64 {
+        let day_ts = start_ts
+            .checked_add(day.checked_mul(DAY_IN_SECONDS).ok_or(Error::MathOverFlow)?)
+            .ok_or(Error::MathOverFlow)?;
+
+        let mut day_price = car.price_per_day;
+
+        if let Some(season) = rules
+            .seasons
+            .iter()
+            .find(|season| season.start_ts <= day_ts && day_ts < season.end_ts)
+        {
+            day_price = apply_percentage(day_price, season.multiplier)?;
+        }
+
+        if is_weekend(day_ts) {
+            let surcharge = apply_percentage(day_price, rules.weekend_surcharge)?;
+            day_price = day_price.checked_add(surcharge).ok_or(Error::MathOverFlow)?;
+        }
+
+        total = total.checked_add(day_price).ok_or(Error::MathOverFlow)?;
+    }
+
+    // Se aplica el mayor descuento por estadía larga que corresponda
+    let discount = rules
+        .long_stay_discounts
+        .iter()
+        .filter(|rule| stay_days >= rule.min_days)
+        .map(|rule| rule.discount)
+        .max()
+        .unwrap_or(0);
+
+    total
+        .checked_sub(apply_percentage(total, discount)?)
+        .ok_or(Error::MathOverFlow)
+}
+
+/// Calcula el precio de un alquiler evaluando las reglas día por día a partir de `start_ts`
+pub fn calculate_rental_price(
+    car: &Car,
+    rules: &PricingRules,
+    start_ts: u64,
+    total_days: u32,
+) -> Result<i128, Error> {
+    price_days(car, rules, start_ts, total_days, total_days)
+}
+
+/// Calcula el precio de los días extra de una extensión: cada día se evalúa desde `start_ts` y el
+/// descuento por estadía larga corresponde a la duración combinada con los `booked_days` ya pagados.
+/// Los días originales no se recotizan.
+pub fn calculate_extension_price(
+    car: &Car,
+    rules: &PricingRules,
+    start_ts: u64,
+    booked_days: u32,
+    extra_days: u32,
+) -> Result<i128, Error> {
+    let stay_days = booked_days.checked_add(extra_days).ok_or(Error::MathOverFlow)?;
+    price_days(car, rules, start_ts, extra_days, stay_days)
+}
+
+/// Cotiza un alquiler que comienza en el timestamp actual del ledger
+pub fn quote_rental(
+    env: &Env,
+    car: &Car,
+    rules: &PricingRules,
+    total_days: u32,
+) -> Result<i128, Error> {
+    calculate_rental_price(car, rules, env.ledger().timestamp(), total_days)
+}
+
+/// Valida que las reglas sean coherentes antes de guardarlas
+pub fn validate_pricing_rules(rules: &PricingRules) -> Result<(), Error> {
+    for season in rules.seasons.iter() {
+        if season.start_ts >= season.end_ts || season.multiplier == 0 {
+            return Err(Error::InvalidParameters);
+        }
+    }
+
+    for rule in rules.long_stay_discounts.iter() {
+        if rule.min_days == 0 || rule.discount > 100 {
+            return Err(Error::InvalidParameters);
+        }
+    }
+
+    Ok(())
+}
\ No newline at end of file
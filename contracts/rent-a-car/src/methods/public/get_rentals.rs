@@ -0,0 +1,48 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    constants::MAX_PAGE_SIZE,
+    storage::{
+        rental::{
+            read_car_rental_count, read_car_rental_id, read_rental, read_renter_rental_count,
+            read_renter_rental_id,
+        },
+        structs::rental::Rental,
+        types::error::Error,
+    },
+};
+
+/// Recorre un índice de alquileres desde `start`, devolviendo como máximo `limit` registros
+fn paginate<F>(env: &Env, count: u32, start: u32, limit: u32, read_id: F) -> Result<Vec<Rental>, Error>
+where
+    F: Fn(u32) -> Result<u64, Error>,
+{
+    let mut rentals = Vec::new(env);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+    for index in start..end {
+        rentals.push_back(read_rental(env, read_id(index)?)?);
+    }
+
+    Ok(rentals)
+}
+
+pub fn get_rentals_by_renter(
+    env: &Env,
+    renter: &Address,
+    start: u32,
+    limit: u32,
+) -> Result<Vec<Rental>, Error> {
+    let count = read_renter_rental_count(env, renter);
+    paginate(env, count, start, limit, |index| read_renter_rental_id(env, renter, index))
+}
+
+pub fn get_rentals_by_car(
+    env: &Env,
+    owner: &Address,
+    start: u32,
+    limit: u32,
+) -> Result<Vec<Rental>, Error> {
+    let count = read_car_rental_count(env, owner);
+    paginate(env, count, start, limit, |index| read_car_rental_id(env, owner, index))
+}
\ No newline at end of file
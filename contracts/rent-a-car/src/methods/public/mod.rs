@@ -1 +1,2 @@
-pub mod get_car_status;
\ No newline at end of file
+pub mod get_car_status;
+pub mod get_rentals;
\ No newline at end of file
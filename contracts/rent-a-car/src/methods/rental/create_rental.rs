@@ -0,0 +1,259 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    constants::DAY_IN_SECONDS,
+    events,
+    methods::{
+        car_token::ownership::car_holder,
+        organization::spending::charge_member,
+        pricing::{promo_code::redeem_promo_code, quote::quote_rental},
+        token::token::{token_transfer, token_transfer_from},
+        verification::credential::require_verified_renter,
+    },
+    storage::{
+        blocklist::is_blocked,
+        car::{read_car, write_car},
+        comission::{read_accumulated_commission, write_accumulated_commission},
+        contract_balance::{read_contract_balance, write_contract_balance},
+        insurance::{read_insurance_config, read_insurance_pool_balance, write_insurance_pool_balance},
+        organization::{read_organization, read_treasury_organization},
+        pricing_rules::read_pricing_rules,
+        rental::{index_rental, next_rental_id, write_car_active_rental, write_rental},
+        reputation::read_reputation,
+        structs::{
+            credential::Credential,
+            rental::{PaymentSource, Rental},
+            rental_options::RentalOptions,
+        },
+        types::{car_status::CarStatus, error::Error, rental_status::RentalStatus},
+    },
+};
+
+/// Cobra `amount` del origen de fondos del alquiler y devuelve la dirección que pagó.
+/// Las firmas del renter o del sponsor las exige quien llama.
+pub fn collect_payment(
+    env: &Env,
+    renter: &Address,
+    payment: &PaymentSource,
+    amount: i128,
+) -> Result<Address, Error> {
+    match payment {
+        PaymentSource::Renter => {
+            token_transfer(env, renter, &env.current_contract_address(), &amount)?;
+            Ok(renter.clone())
+        }
+        PaymentSource::Sponsor(spender) => {
+            token_transfer_from(env, spender, renter, &env.current_contract_address(), &amount)?;
+            Ok(renter.clone())
+        }
+        PaymentSource::Organization(org) => {
+            let organization = read_organization(env, org)?;
+
+            if read_treasury_organization(env, &organization.treasury) != Some(org.clone()) {
+                return Err(Error::Unauthorized);
+            }
+
+            // El gasto se imputa al límite del miembro antes de debitar la tesorería
+            charge_member(env, org, renter, amount)?;
+
+            let contract = env.current_contract_address();
+            token_transfer_from(env, &contract, &organization.treasury, &contract, &amount)?;
+            Ok(organization.treasury)
+        }
+    }
+}
+
+/// Valida, cobra y registra un nuevo alquiler. La autorización del renter la exige quien llama.
+#[allow(clippy::too_many_arguments)]
+pub fn create_rental(
+    env: &Env,
+    renter: &Address,
+    owner: &Address,
+    total_days_to_rent: u32,
+    amount: i128,
+    options: &RentalOptions,
+    credential: &Option<Credential>,
+    payment: &PaymentSource,
+) -> Result<Rental, Error> {
+    if amount <= 0 {
+        return Err(Error::AmountMustBePositive);
+    }
+
+    if total_days_to_rent == 0 {
+        return Err(Error::RentalDurationCannotBeZero);
+    }
+
+    // Las reglas sobre el owner se aplican a quien tiene hoy el NFT del auto
+    let holder = car_holder(env, owner);
+
+    if *renter == holder {
+        return Err(Error::SelfRentalNotAllowed);
+    }
+
+    if is_blocked(env, renter) {
+        return Err(Error::AddressBlocked);
+    }
+
+    if is_blocked(env, &holder) {
+        return Err(Error::AddressBlocked);
+    }
+
+    let mut car = read_car(env, owner)?;
+
+    // Las publicaciones pendientes de aprobación no se pueden alquilar
+    if car.car_status == CarStatus::PendingApproval {
+        return Err(Error::ListingNotApproved);
+    }
+
+    if car.car_status != CarStatus::Available {
+        return Err(Error::CarAlreadyRented);
+    }
+
+    // Algunos autos exigen que el renter tenga su licencia verificada
+    require_verified_renter(env, renter, &car, credential)?;
+
+    // El promedio del renter debe alcanzar el mínimo exigido por el owner
+    if car.min_renter_rating > 0 {
+        let reputation = read_reputation(env, renter);
+        let required = (car.min_renter_rating as u64) * (reputation.count as u64);
+
+        if reputation.count == 0 || (reputation.sum as u64) < required {
+            return Err(Error::RenterRatingTooLow);
+        }
+    }
+
+    // `amount` es el máximo que el arrendatario acepta pagar; se cobra el precio cotizado
+    let rules = read_pricing_rules(env, owner);
+    let mut price = quote_rental(env, &car, &rules, total_days_to_rent)?;
+
+    // El descuento promocional se aplica antes de calcular la comisión
+    if let Some(code) = &options.promo_code {
+        price = redeem_promo_code(env, code, renter, owner, price)?;
+    }
+
+    if amount < price {
+        return Err(Error::PaymentBelowQuote);
+    }
+
+    let amount = price;
+
+    // Calcular el monto total incluyendo la comisión
+    let total_to_pay = if car.comission_to_admin > 0 {
+        let commission = car
+            .comission_to_admin
+            .checked_mul(amount)
+            .ok_or(Error::MathOverFlow)?
+            .checked_div(100)
+            .ok_or(Error::MathOverFlow)?;
+
+        amount.checked_add(commission).ok_or(Error::MathOverFlow)?
+    } else {
+        amount
+    };
+
+    // La prima del seguro opcional se calcula sobre el monto del alquiler
+    let insurance_premium = if options.insured {
+        let config = read_insurance_config(env)?;
+        amount
+            .checked_mul(config.premium_percentage as i128)
+            .ok_or(Error::MathOverFlow)?
+            .checked_div(100)
+            .ok_or(Error::MathOverFlow)?
+    } else {
+        0
+    };
+
+    // El depósito de garantía queda retenido en el contrato hasta que se liquide el alquiler
+    let total_to_pay = total_to_pay
+        .checked_add(car.deposit)
+        .ok_or(Error::MathOverFlow)?
+        .checked_add(insurance_premium)
+        .ok_or(Error::MathOverFlow)?;
+
+    // El arrendatario paga el monto total (alquiler + comisión + depósito + seguro)
+    let payer = collect_payment(env, renter, payment, total_to_pay)?;
+    car.car_status = CarStatus::Rented;
+
+    // Calcular y procesar la comisión si está configurada
+    let commission_amount = if car.comission_to_admin > 0 {
+        // Calcular la comisión del admin (porcentaje adicional sobre el monto del alquiler)
+        let commission = car
+            .comission_to_admin
+            .checked_mul(amount)
+            .ok_or(Error::MathOverFlow)?
+            .checked_div(100)
+            .ok_or(Error::MathOverFlow)?;
+
+        // Actualizar las comisiones acumuladas del admin
+        let current_accumulated = read_accumulated_commission(env);
+        let new_accumulated = current_accumulated
+            .checked_add(commission)
+            .ok_or(Error::MathOverFlow)?;
+        write_accumulated_commission(env, &new_accumulated);
+
+        commission
+    } else {
+        0 // Si no hay comisión configurada
+    };
+
+    // Registrar el alquiler con la comisión
+    let start_ts = env.ledger().timestamp();
+    let end_ts = start_ts
+        .checked_add(
+            (total_days_to_rent as u64)
+                .checked_mul(DAY_IN_SECONDS)
+                .ok_or(Error::MathOverFlow)?,
+        )
+        .ok_or(Error::MathOverFlow)?;
+
+    // El alquiler queda reservado hasta que ambas partes confirmen la entrega del auto
+    let rental = Rental {
+        id: next_rental_id(env),
+        renter: renter.clone(),
+        payer,
+        payment: payment.clone(),
+        owner: owner.clone(),
+        start_ts,
+        end_ts,
+        total_days_to_rent,
+        amount,
+        released: 0,
+        commission: commission_amount,
+        deposit: car.deposit,
+        insurance_premium,
+        overdue_ts: None,
+        status: RentalStatus::Reserved,
+        returned_ts: None,
+    };
+
+    // Actualizar el balance del contrato con el monto del alquiler, la comisión, el depósito y la prima
+    let mut contract_balance = read_contract_balance(env);
+    let total_amount = amount
+        .checked_add(commission_amount)
+        .ok_or(Error::MathOverFlow)?
+        .checked_add(rental.deposit)
+        .ok_or(Error::MathOverFlow)?
+        .checked_add(insurance_premium)
+        .ok_or(Error::MathOverFlow)?;
+
+    if insurance_premium > 0 {
+        let pool_balance = read_insurance_pool_balance(env)
+            .checked_add(insurance_premium)
+            .ok_or(Error::MathOverFlow)?;
+        write_insurance_pool_balance(env, &pool_balance);
+    }
+
+    contract_balance = contract_balance
+        .checked_add(total_amount)
+        .ok_or(Error::MathOverFlow)?;
+
+    write_contract_balance(env, &contract_balance);
+    write_car(env, owner, &car);
+    write_rental(env, &rental);
+    index_rental(env, &rental);
+    write_car_active_rental(env, owner, rental.id);
+
+    // Emitir el evento con el monto completo del alquiler
+    events::rental::rented(env, renter.clone(), owner.clone(), total_days_to_rent, amount);
+    Ok(rental)
+}
@@ -0,0 +1,48 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    methods::{rental::earnings::credit_owner_revenue, token::token::token_transfer},
+    storage::{
+        contract_balance::{read_contract_balance, write_contract_balance},
+        rental::{read_car_rental_count, read_car_rental_id, read_rental},
+        structs::rental::Rental,
+        types::{error::Error, rental_status::RentalStatus},
+    },
+};
+
+/// Indica si algún alquiler del auto todavía retiene fondos: un depósito sin liberar o un reclamo abierto
+pub fn has_unsettled_rentals(env: &Env, owner: &Address) -> Result<bool, Error> {
+    for index in 0..read_car_rental_count(env, owner) {
+        let rental = read_rental(env, read_car_rental_id(env, owner, index)?)?;
+        if rental.status != RentalStatus::Settled && rental.status != RentalStatus::Cancelled {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Libera el depósito de un alquiler: `owner_share` se acredita al owner y el resto vuelve a quien pagó el alquiler
+pub fn settle_deposit(env: &Env, rental: &Rental, owner_share: i128) -> Result<(), Error> {
+    if owner_share < 0 || owner_share > rental.deposit {
+        return Err(Error::InvalidParameters);
+    }
+
+    credit_owner_revenue(env, &rental.owner, owner_share)?;
+
+    let refund = rental
+        .deposit
+        .checked_sub(owner_share)
+        .ok_or(Error::MathOverFlow)?;
+
+    if refund > 0 {
+        let contract_balance = read_contract_balance(env)
+            .checked_sub(refund)
+            .ok_or(Error::MathOverFlow)?;
+
+        token_transfer(env, &env.current_contract_address(), &rental.payer, &refund)?;
+        write_contract_balance(env, &contract_balance);
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    constants::DAY_IN_SECONDS,
+    events,
+    methods::{
+        payout::{auto_payout::try_auto_payout, revenue_split::split_revenue},
+        shares::dividends::distribute_to_shareholders,
+    },
+    storage::{
+        car::{read_car, write_car},
+        handover::read_pickup,
+        structs::rental::Rental,
+        types::error::Error,
+    },
+};
+
+/// Acredita al saldo retirable del owner la parte del alquiler que aún estaba retenida y, si
+/// corresponde, se la transfiere automáticamente.
+/// Con `full` se libera todo; si no, la proporción de días transcurridos desde la entrega.
+pub fn release_earnings(env: &Env, rental: &mut Rental, full: bool) -> Result<i128, Error> {
+    let earned = if full {
+        rental.amount
+    } else {
+        let picked_up_ts = read_pickup(env, rental.id)
+            .map(|pickup| pickup.timestamp)
+            .unwrap_or(rental.start_ts);
+        let elapsed_days = (env.ledger().timestamp().saturating_sub(picked_up_ts) / DAY_IN_SECONDS)
+            .min(rental.total_days_to_rent as u64);
+
+        rental
+            .amount
+            .checked_mul(elapsed_days as i128)
+            .ok_or(Error::MathOverFlow)?
+            .checked_div(rental.total_days_to_rent as i128)
+            .ok_or(Error::MathOverFlow)?
+    };
+
+    let to_release = earned.checked_sub(rental.released).ok_or(Error::MathOverFlow)?;
+    if to_release <= 0 {
+        return Ok(0);
+    }
+
+    rental.released = earned;
+
+    events::earnings::earnings_released(env, rental.id, rental.owner.clone(), to_release);
+    credit_owner_revenue(env, &rental.owner, to_release)?;
+    Ok(to_release)
+}
+
+/// Acredita un ingreso del lado del owner (alquiler, penalidades, excedentes o daños). Si el auto
+/// tiene inversores o copropietarios, se reparte entre ellos; si no, va al saldo retirable del
+/// titular y, si corresponde, se le transfiere automáticamente.
+pub fn credit_owner_revenue(env: &Env, owner: &Address, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Ok(());
+    }
+
+    if distribute_to_shareholders(env, owner, amount)? || split_revenue(env, owner, amount)? {
+        return Ok(());
+    }
+
+    let mut car = read_car(env, owner)?;
+    car.available_to_withdraw = car
+        .available_to_withdraw
+        .checked_add(amount)
+        .ok_or(Error::MathOverFlow)?;
+    write_car(env, owner, &car);
+
+    try_auto_payout(env, owner, amount)?;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use soroban_sdk::Env;
+
+use crate::{
+    methods::rental::earnings::credit_owner_revenue,
+    storage::{
+        insurance::{read_insurance_config, read_insurance_pool_balance, write_insurance_pool_balance},
+        structs::rental::Rental,
+        types::error::Error,
+    },
+};
+
+/// Paga al owner la parte del reclamo que cubre el fondo de seguros y devuelve lo que queda
+/// a cargo del depósito del renter
+pub fn pay_claim_from_insurance(env: &Env, rental: &Rental, owner_share: i128) -> Result<i128, Error> {
+    if rental.insurance_premium == 0 || owner_share <= 0 {
+        return Ok(owner_share);
+    }
+
+    let config = read_insurance_config(env)?;
+    let pool_balance = read_insurance_pool_balance(env);
+    let covered = owner_share.min(config.coverage_limit).min(pool_balance);
+
+    if covered > 0 {
+        credit_owner_revenue(env, &rental.owner, covered)?;
+
+        let pool_balance = pool_balance.checked_sub(covered).ok_or(Error::MathOverFlow)?;
+        write_insurance_pool_balance(env, &pool_balance);
+    }
+
+    owner_share.checked_sub(covered).ok_or(Error::MathOverFlow)
+}
\ No newline at end of file
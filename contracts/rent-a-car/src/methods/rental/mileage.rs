@@ -0,0 +1,78 @@
+use soroban_sdk::Env;
+
+use crate::{
+    events,
+    methods::{rental::earnings::credit_owner_revenue, token::token::token_transfer},
+    storage::{
+        car::read_car,
+        contract_balance::{read_contract_balance, write_contract_balance},
+        odometer::{read_odometer, write_odometer},
+        structs::rental::Rental,
+        types::{error::Error, rental_status::RentalStatus},
+    },
+};
+
+/// Registra una lectura del reporter de telemetría: antes de la entrega fija el inicio y
+/// durante el alquiler actualiza la última lectura
+pub fn record_reporter_odometer(env: &Env, rental: &Rental, odometer: u64) -> Result<(), Error> {
+    let mut readings = read_odometer(env, rental.id);
+
+    match rental.status {
+        RentalStatus::Reserved => readings.start = Some(odometer),
+        RentalStatus::Active | RentalStatus::Overdue => readings.end = Some(odometer),
+        _ => return Err(Error::RentalNotActive),
+    }
+
+    write_odometer(env, rental.id, &readings);
+    Ok(())
+}
+
+/// Cobra el excedente de kilometraje al owner: primero se descuenta del depósito y, si el renter
+/// firmó la devolución, el resto se le transfiere directamente. Devuelve el monto cobrado.
+pub fn charge_mileage_overage(env: &Env, rental: &mut Rental, renter_signed: bool) -> Result<i128, Error> {
+    let car = read_car(env, &rental.owner)?;
+
+    if car.included_km_per_day == 0 {
+        return Ok(0);
+    }
+
+    let readings = read_odometer(env, rental.id);
+    let (Some(start), Some(end)) = (readings.start, readings.end) else {
+        return Ok(0);
+    };
+
+    let included_km = (car.included_km_per_day as u64)
+        .checked_mul(rental.total_days_to_rent as u64)
+        .ok_or(Error::MathOverFlow)?;
+    let extra_km = end.saturating_sub(start).saturating_sub(included_km);
+
+    if extra_km == 0 {
+        return Ok(0);
+    }
+
+    let overage = car
+        .overage_fee_per_km
+        .checked_mul(extra_km as i128)
+        .ok_or(Error::MathOverFlow)?;
+
+    let from_deposit = overage.min(rental.deposit);
+    let mut charged = from_deposit;
+
+    let remaining = overage.checked_sub(from_deposit).ok_or(Error::MathOverFlow)?;
+    if remaining > 0 && renter_signed {
+        token_transfer(env, &rental.renter, &env.current_contract_address(), &remaining)?;
+
+        let contract_balance = read_contract_balance(env)
+            .checked_add(remaining)
+            .ok_or(Error::MathOverFlow)?;
+        write_contract_balance(env, &contract_balance);
+
+        charged = overage;
+    }
+
+    rental.deposit = rental.deposit.checked_sub(from_deposit).ok_or(Error::MathOverFlow)?;
+    credit_owner_revenue(env, &rental.owner, charged)?;
+
+    events::mileage::mileage_overage_charged(env, rental.id, extra_km, charged);
+    Ok(charged)
+}
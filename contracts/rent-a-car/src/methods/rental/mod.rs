@@ -0,0 +1,8 @@
+pub mod status;
+pub mod deposit;
+pub mod insurance;
+pub mod create_rental;
+pub mod overdue;
+pub mod mileage;
+pub mod earnings;
+pub mod reservation;
\ No newline at end of file
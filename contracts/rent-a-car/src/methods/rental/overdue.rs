@@ -0,0 +1,75 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    constants::DAY_IN_SECONDS,
+    events,
+    methods::{rental::earnings::credit_owner_revenue, token::token::token_transfer},
+    storage::{
+        car::read_car,
+        contract_balance::{read_contract_balance, write_contract_balance},
+        structs::{car::Car, overdue_policy::OverduePolicy, rental::Rental},
+        types::error::Error,
+    },
+};
+
+/// Penalidad por atraso acumulada hasta ahora. Se cobra del depósito y nunca lo excede.
+pub fn calculate_late_penalty(
+    env: &Env,
+    rental: &Rental,
+    car: &Car,
+    policy: &OverduePolicy,
+) -> Result<i128, Error> {
+    let late_seconds = env.ledger().timestamp().saturating_sub(rental.end_ts);
+    let late_days = late_seconds.div_ceil(DAY_IN_SECONDS) as i128;
+
+    Ok(car
+        .price_per_day
+        .checked_mul(late_days)
+        .ok_or(Error::MathOverFlow)?
+        .checked_mul(policy.penalty_percentage as i128)
+        .ok_or(Error::MathOverFlow)?
+        .checked_div(100)
+        .ok_or(Error::MathOverFlow)?
+        .min(rental.deposit))
+}
+
+/// Parte de la penalidad que cobra quien marca el alquiler como vencido
+pub fn calculate_keeper_reward(penalty: i128, policy: &OverduePolicy) -> Result<i128, Error> {
+    penalty
+        .checked_mul(policy.keeper_reward_percentage as i128)
+        .ok_or(Error::MathOverFlow)?
+        .checked_div(100)
+        .ok_or(Error::MathOverFlow)
+}
+
+/// Cobra del depósito la penalidad acumulada al marcar el alquiler como vencido: el keeper cobra
+/// su recompensa y el resto se le acredita al owner. El atraso posterior no suma penalidad, así el
+/// owner no puede quedarse con el depósito demorando la devolución. Devuelve la penalidad y la
+/// recompensa.
+pub fn charge_late_penalty(
+    env: &Env,
+    rental: &mut Rental,
+    keeper: &Address,
+    policy: &OverduePolicy,
+) -> Result<(i128, i128), Error> {
+    let car = read_car(env, &rental.owner)?;
+    let penalty = calculate_late_penalty(env, rental, &car, policy)?;
+    let reward = calculate_keeper_reward(penalty, policy)?;
+
+    if reward > 0 {
+        let contract_balance = read_contract_balance(env)
+            .checked_sub(reward)
+            .ok_or(Error::MathOverFlow)?;
+
+        token_transfer(env, &env.current_contract_address(), keeper, &reward)?;
+        write_contract_balance(env, &contract_balance);
+    }
+
+    let owner_share = penalty.checked_sub(reward).ok_or(Error::MathOverFlow)?;
+    credit_owner_revenue(env, &rental.owner, owner_share)?;
+
+    rental.deposit = rental.deposit.checked_sub(penalty).ok_or(Error::MathOverFlow)?;
+
+    events::overdue::late_penalty_charged(env, rental.id, owner_share);
+    Ok((penalty, reward))
+}
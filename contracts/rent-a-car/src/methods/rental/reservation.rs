@@ -0,0 +1,96 @@
+use soroban_sdk::Env;
+
+use crate::{
+    constants::DAY_IN_SECONDS,
+    methods::{
+        rental::{earnings::release_earnings, status::transition_rental},
+        token::token::token_transfer,
+    },
+    storage::{
+        car::{read_car, write_car},
+        comission::{read_accumulated_commission, write_accumulated_commission},
+        contract_balance::{read_contract_balance, write_contract_balance},
+        insurance::{read_insurance_pool_balance, write_insurance_pool_balance},
+        rental::{remove_car_active_rental, write_rental},
+        structs::rental::Rental,
+        types::{car_status::CarStatus, error::Error, rental_status::RentalStatus},
+    },
+};
+
+/// Tarifa de cancelación: el alquiler de los días que el auto estuvo reservado, redondeados hacia
+/// arriba. Solo es gratuita en el mismo momento en que se reservó.
+pub fn calculate_cancellation_fee(env: &Env, rental: &Rental) -> Result<i128, Error> {
+    let held_days = env
+        .ledger()
+        .timestamp()
+        .saturating_sub(rental.start_ts)
+        .div_ceil(DAY_IN_SECONDS)
+        .min(rental.total_days_to_rent as u64);
+
+    rental
+        .amount
+        .checked_mul(held_days as i128)
+        .ok_or(Error::MathOverFlow)?
+        .checked_div(rental.total_days_to_rent as i128)
+        .ok_or(Error::MathOverFlow)
+}
+
+/// Cancela una reserva que no se retiró. Con `charge_fee`, el owner cobra la tarifa de cancelación;
+/// el resto del pago (incluidas la comisión y la prima) vuelve a quien pagó. Devuelve el monto
+/// reembolsado.
+pub fn cancel_reservation(env: &Env, rental: &mut Rental, charge_fee: bool) -> Result<i128, Error> {
+    transition_rental(rental, RentalStatus::Cancelled)?;
+
+    let fee = if charge_fee {
+        calculate_cancellation_fee(env, rental)?
+    } else {
+        0
+    };
+
+    let total_to_refund = rental
+        .amount
+        .checked_sub(fee)
+        .ok_or(Error::MathOverFlow)?
+        .checked_add(rental.commission)
+        .ok_or(Error::MathOverFlow)?
+        .checked_add(rental.deposit)
+        .ok_or(Error::MathOverFlow)?
+        .checked_add(rental.insurance_premium)
+        .ok_or(Error::MathOverFlow)?;
+
+    // Se revierten la comisión del admin y la prima del seguro
+    let accumulated_commission = read_accumulated_commission(env)
+        .checked_sub(rental.commission)
+        .ok_or(Error::MathOverFlow)?;
+
+    let contract_balance = read_contract_balance(env)
+        .checked_sub(total_to_refund)
+        .ok_or(Error::MathOverFlow)?;
+
+    let pool_balance = read_insurance_pool_balance(env)
+        .checked_sub(rental.insurance_premium)
+        .ok_or(Error::MathOverFlow)?;
+
+    token_transfer(
+        env,
+        &env.current_contract_address(),
+        &rental.payer,
+        &total_to_refund,
+    )?;
+
+    write_accumulated_commission(env, &accumulated_commission);
+    write_contract_balance(env, &contract_balance);
+    write_insurance_pool_balance(env, &pool_balance);
+
+    // Tras la cancelación, el alquiler solo cuesta la tarifa, que se le libera al owner
+    rental.amount = fee;
+    release_earnings(env, rental, true)?;
+
+    let mut car = read_car(env, &rental.owner)?;
+    car.car_status = CarStatus::Available;
+    write_car(env, &rental.owner, &car);
+    write_rental(env, rental);
+    remove_car_active_rental(env, &rental.owner);
+
+    Ok(total_to_refund)
+}
@@ -0,0 +1,33 @@
+use crate::storage::{
+    structs::rental::Rental,
+    types::{error::Error, rental_status::RentalStatus},
+};
+
+/// Transiciones permitidas entre estados de un alquiler
+pub fn can_transition(from: RentalStatus, to: RentalStatus) -> bool {
+    use RentalStatus::*;
+
+    matches!(
+        (from, to),
+        (Reserved, Active)
+            | (Reserved, Cancelled)
+            | (Active, Returned)
+            | (Active, Overdue)
+            | (Overdue, Returned)
+            | (Overdue, Disputed)
+            | (Overdue, Settled)
+            | (Returned, Disputed)
+            | (Returned, Settled)
+            | (Disputed, Settled)
+    )
+}
+
+/// Único punto donde se cambia el estado de un alquiler
+pub fn transition_rental(rental: &mut Rental, to: RentalStatus) -> Result<(), Error> {
+    if !can_transition(rental.status, to) {
+        return Err(Error::InvalidRentalTransition);
+    }
+
+    rental.status = to;
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    constants::SHARE_REVENUE_PRECISION,
+    methods::shares::share_token::ShareTokenClient,
+    storage::{
+        shares::{read_car_shares, write_car_shares},
+        structs::shares::{CarShares, ShareHolding},
+        types::error::Error,
+    },
+};
+
+/// Suma `amount` al ingreso acumulado por participación del auto, sin recorrer a los titulares.
+/// Devuelve `false` si el auto no tiene participaciones en circulación.
+pub fn distribute_to_shareholders(env: &Env, car: &Address, amount: i128) -> Result<bool, Error> {
+    let Some(mut shares) = read_car_shares(env, car) else {
+        return Ok(false);
+    };
+
+    let supply = ShareTokenClient::new(env, &shares.token).total_supply();
+    if supply == 0 {
+        return Ok(false);
+    }
+
+    let scaled = amount
+        .checked_mul(SHARE_REVENUE_PRECISION)
+        .and_then(|scaled| scaled.checked_add(shares.dust))
+        .ok_or(Error::MathOverFlow)?;
+
+    shares.revenue_per_share = shares
+        .revenue_per_share
+        .checked_add(scaled / supply)
+        .ok_or(Error::MathOverFlow)?;
+    shares.dust = scaled % supply;
+
+    write_car_shares(env, car, &shares);
+    Ok(true)
+}
+
+/// Liquida en `owed` lo que le corresponde al titular desde su última liquidación, según las
+/// participaciones que tuvo en ese período
+pub fn settle_holding(shares: &CarShares, holding: &mut ShareHolding, balance: i128) -> Result<(), Error> {
+    let accrued = shares
+        .revenue_per_share
+        .checked_sub(holding.revenue_per_share_paid)
+        .and_then(|delta| delta.checked_mul(balance))
+        .ok_or(Error::MathOverFlow)?
+        / SHARE_REVENUE_PRECISION;
+
+    holding.owed = holding.owed.checked_add(accrued).ok_or(Error::MathOverFlow)?;
+    holding.revenue_per_share_paid = shares.revenue_per_share;
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Funciones del token de participaciones (contrato car-shares) que usa este contrato. El token
+/// también implementa SEP-41; sus transferencias se liquidan en `settle_share_holders`.
+#[contractclient(name = "ShareTokenClient")]
+#[allow(dead_code)] // solo se usa el cliente generado
+pub trait ShareToken {
+    fn admin(env: Env) -> Address;
+    fn car(env: Env) -> Address;
+    fn total_supply(env: Env) -> i128;
+    fn balance(env: Env, id: Address) -> i128;
+    fn mint(env: Env, to: Address, amount: i128);
+}
@@ -10,4 +10,24 @@ pub fn token_transfer(env: &Env, from: &Address, to: &Address, amount: &i128) ->
     let token = token::TokenClient::new(env, &token_address);
     token.transfer(from, to, amount);
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn token_transfer_from(
+    env: &Env,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+    amount: &i128,
+) -> Result<(), Error> {
+    let token_address = read_token(env)?;
+    let token = token::TokenClient::new(env, &token_address);
+    token.transfer_from(spender, from, to, amount);
+    Ok(())
+}
+
+/// Igual que `token_transfer`, pero devuelve `false` en lugar de abortar si la transferencia falla
+pub fn try_token_transfer(env: &Env, from: &Address, to: &Address, amount: &i128) -> Result<bool, Error> {
+    let token_address = read_token(env)?;
+    let token = token::TokenClient::new(env, &token_address);
+    Ok(matches!(token.try_transfer(from, to, amount), Ok(Ok(()))))
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{attestation::read_attestation, verifier::is_verifier};
+
+/// Una atestación es válida si no expiró y el verificador que la emitió sigue habilitado
+pub fn has_valid_attestation(env: &Env, renter: &Address) -> bool {
+    match read_attestation(env, renter) {
+        Ok(attestation) => {
+            attestation.expires_at > env.ledger().timestamp()
+                && is_verifier(env, &attestation.verifier)
+        }
+        Err(_) => false,
+    }
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use soroban_sdk::{xdr::ToXdr, Address, Env};
+
+use crate::{
+    methods::verification::attestation::has_valid_attestation,
+    storage::{
+        structs::{car::Car, credential::Credential},
+        types::error::Error,
+        verifier::is_verifier_key,
+    },
+};
+
+/// Verifica la firma ed25519 de una credencial emitida para `renter` en este contrato.
+/// Una firma inválida aborta la invocación.
+pub fn verify_credential(env: &Env, renter: &Address, credential: &Credential) -> Result<(), Error> {
+    if !is_verifier_key(env, &credential.public_key) {
+        return Err(Error::RenterNotVerified);
+    }
+
+    if credential.expires_at <= env.ledger().timestamp() {
+        return Err(Error::RenterNotVerified);
+    }
+
+    // La dirección del contrato evita que una credencial firmada para otro despliegue se reutilice aquí
+    let message = (
+        env.current_contract_address(),
+        renter.clone(),
+        credential.license_class,
+        credential.expires_at,
+    )
+        .to_xdr(env);
+    env.crypto()
+        .ed25519_verify(&credential.public_key, &message, &credential.signature);
+
+    Ok(())
+}
+
+/// El renter está verificado si tiene una atestación vigente o presenta una credencial firmada válida.
+/// Si el auto exige una clase de licencia, solo sirve una credencial de esa clase: las atestaciones
+/// no registran la clase.
+pub fn require_verified_renter(
+    env: &Env,
+    renter: &Address,
+    car: &Car,
+    credential: &Option<Credential>,
+) -> Result<(), Error> {
+    if !car.requires_verification && car.required_license_class == 0 {
+        return Ok(());
+    }
+
+    if car.required_license_class == 0 && has_valid_attestation(env, renter) {
+        return Ok(());
+    }
+
+    match credential {
+        Some(credential)
+            if car.required_license_class == 0
+                || credential.license_class == car.required_license_class =>
+        {
+            verify_credential(env, renter, credential)
+        }
+        _ => Err(Error::RenterNotVerified),
+    }
+}
@@ -0,0 +1,2 @@
+pub mod attestation;
+pub mod credential;
\ No newline at end of file
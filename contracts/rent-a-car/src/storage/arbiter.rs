@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::{error::Error, storage::DataKey};
+
+pub(crate) fn read_arbiter(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Arbiter).ok_or(Error::NotFound)
+}
+
+pub(crate) fn write_arbiter(env: &Env, arbiter: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Arbiter, arbiter);
+}
\ No newline at end of file
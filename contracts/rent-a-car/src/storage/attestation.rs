@@ -0,0 +1,25 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::attestation::Attestation,
+    types::{error::Error, storage::DataKey},
+};
+
+pub(crate) fn read_attestation(env: &Env, renter: &Address) -> Result<Attestation, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Attestation(renter.clone()))
+        .ok_or(Error::NotFound)
+}
+
+pub(crate) fn write_attestation(env: &Env, renter: &Address, attestation: &Attestation) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Attestation(renter.clone()), attestation);
+}
+
+pub(crate) fn remove_attestation(env: &Env, renter: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Attestation(renter.clone()));
+}
\ No newline at end of file
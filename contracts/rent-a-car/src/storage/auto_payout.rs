@@ -0,0 +1,21 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+pub(crate) fn has_auto_payout(env: &Env, owner: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::AutoPayout(owner.clone()))
+}
+
+pub(crate) fn write_auto_payout(env: &Env, owner: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AutoPayout(owner.clone()), &true);
+}
+
+pub(crate) fn remove_auto_payout(env: &Env, owner: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AutoPayout(owner.clone()));
+}
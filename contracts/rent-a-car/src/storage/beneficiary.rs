@@ -0,0 +1,35 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{structs::beneficiary::Beneficiary, types::storage::DataKey};
+
+pub(crate) fn read_beneficiaries(env: &Env, owner: &Address) -> Vec<Beneficiary> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Beneficiaries(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_beneficiaries(env: &Env, owner: &Address, beneficiaries: &Vec<Beneficiary>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Beneficiaries(owner.clone()), beneficiaries);
+}
+
+pub(crate) fn remove_beneficiaries(env: &Env, owner: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Beneficiaries(owner.clone()));
+}
+
+pub(crate) fn read_beneficiary_balance(env: &Env, owner: &Address, beneficiary: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BeneficiaryBalance(owner.clone(), beneficiary.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_beneficiary_balance(env: &Env, owner: &Address, beneficiary: &Address, amount: &i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::BeneficiaryBalance(owner.clone(), beneficiary.clone()), amount);
+}
@@ -0,0 +1,21 @@
+use soroban_sdk::{Address, Env, String};
+
+use crate::storage::types::storage::DataKey;
+
+pub(crate) fn is_blocked(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Blocked(address.clone()))
+}
+
+pub(crate) fn write_blocked(env: &Env, address: &Address, reason: &String) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Blocked(address.clone()), reason);
+}
+
+pub(crate) fn remove_blocked(env: &Env, address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Blocked(address.clone()));
+}
\ No newline at end of file
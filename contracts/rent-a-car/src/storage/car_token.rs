@@ -0,0 +1,47 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::{error::Error, storage::DataKey};
+
+/// Reserva el siguiente identificador de token (los ids comienzan en 1)
+pub(crate) fn next_token_id(env: &Env) -> u32 {
+    let id = env.storage().instance().get(&DataKey::CarTokenCounter).unwrap_or(0_u32) + 1;
+    env.storage().instance().set(&DataKey::CarTokenCounter, &id);
+    id
+}
+
+pub(crate) fn read_car_token(env: &Env, car: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarToken(car.clone()))
+}
+
+pub(crate) fn write_car_token(env: &Env, car: &Address, token_id: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarToken(car.clone()), &token_id);
+}
+
+pub(crate) fn remove_car_token(env: &Env, car: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CarToken(car.clone()));
+}
+
+pub(crate) fn read_token_car(env: &Env, token_id: u32) -> Result<Address, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenCar(token_id))
+        .ok_or(Error::NotFound)
+}
+
+pub(crate) fn write_token_car(env: &Env, token_id: u32, car: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenCar(token_id), car);
+}
+
+pub(crate) fn remove_token_car(env: &Env, token_id: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TokenCar(token_id));
+}
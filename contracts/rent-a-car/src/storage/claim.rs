@@ -0,0 +1,14 @@
+use soroban_sdk::Env;
+
+use crate::storage::{structs::claim::Claim, types::{error::Error, storage::DataKey}};
+
+pub(crate) fn read_claim(env: &Env, rental_id: u64) -> Result<Claim, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Claim(rental_id))
+        .ok_or(Error::NotFound)
+}
+
+pub(crate) fn write_claim(env: &Env, rental_id: u64, claim: &Claim) {
+    env.storage().persistent().set(&DataKey::Claim(rental_id), claim);
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::types::storage::DataKey;
+
+pub(crate) fn read_drivers(env: &Env, rental_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RentalDrivers(rental_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_drivers(env: &Env, rental_id: u64, drivers: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RentalDrivers(rental_id), drivers);
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+pub(crate) fn is_fleet_manager(env: &Env, manager: &Address) -> bool {
+    env.storage().instance().has(&DataKey::FleetManager(manager.clone()))
+}
+
+pub(crate) fn write_fleet_manager(env: &Env, manager: &Address) {
+    env.storage().instance().set(&DataKey::FleetManager(manager.clone()), &true);
+}
+
+pub(crate) fn remove_fleet_manager(env: &Env, manager: &Address) {
+    env.storage().instance().remove(&DataKey::FleetManager(manager.clone()));
+}
\ No newline at end of file
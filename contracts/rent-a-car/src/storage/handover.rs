@@ -0,0 +1,28 @@
+use soroban_sdk::Env;
+
+use crate::storage::{
+    structs::handover::Handover,
+    types::{error::Error, storage::DataKey},
+};
+
+pub(crate) fn read_pickup(env: &Env, rental_id: u64) -> Result<Handover, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Pickup(rental_id))
+        .ok_or(Error::NotFound)
+}
+
+pub(crate) fn write_pickup(env: &Env, rental_id: u64, handover: &Handover) {
+    env.storage().persistent().set(&DataKey::Pickup(rental_id), handover);
+}
+
+pub(crate) fn read_return(env: &Env, rental_id: u64) -> Result<Handover, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Return(rental_id))
+        .ok_or(Error::NotFound)
+}
+
+pub(crate) fn write_return(env: &Env, rental_id: u64, handover: &Handover) {
+    env.storage().persistent().set(&DataKey::Return(rental_id), handover);
+}
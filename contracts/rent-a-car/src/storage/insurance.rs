@@ -0,0 +1,30 @@
+use soroban_sdk::Env;
+
+use crate::storage::{
+    structs::insurance::InsuranceConfig,
+    types::{error::Error, storage::DataKey},
+};
+
+pub(crate) fn read_insurance_config(env: &Env) -> Result<InsuranceConfig, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::InsuranceConfig)
+        .ok_or(Error::NotFound)
+}
+
+pub(crate) fn write_insurance_config(env: &Env, config: &InsuranceConfig) {
+    env.storage().instance().set(&DataKey::InsuranceConfig, config);
+}
+
+pub(crate) fn read_insurance_pool_balance(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InsurancePoolBalance)
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_insurance_pool_balance(env: &Env, amount: &i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::InsurancePoolBalance, amount);
+}
\ No newline at end of file
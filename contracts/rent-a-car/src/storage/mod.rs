@@ -6,3 +6,25 @@ pub mod structs;
 pub mod types;
 pub mod contract_balance;
 pub mod comission;
+pub mod fleet_manager;
+pub mod pricing_rules;
+pub mod promo_code;
+pub mod claim;
+pub mod arbiter;
+pub mod insurance;
+pub mod reputation;
+pub mod verifier;
+pub mod attestation;
+pub mod blocklist;
+
+pub mod driver;
+pub mod organization;
+pub mod overdue_policy;
+pub mod handover;
+pub mod odometer;
+pub mod telematics;
+pub mod auto_payout;
+pub mod payout_destination;
+pub mod beneficiary;
+pub mod car_token;
+pub mod shares;
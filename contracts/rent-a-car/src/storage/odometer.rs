@@ -0,0 +1,16 @@
+use soroban_sdk::Env;
+
+use crate::storage::{structs::odometer::OdometerReadings, types::storage::DataKey};
+
+pub(crate) fn read_odometer(env: &Env, rental_id: u64) -> OdometerReadings {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Odometer(rental_id))
+        .unwrap_or_default()
+}
+
+pub(crate) fn write_odometer(env: &Env, rental_id: u64, readings: &OdometerReadings) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Odometer(rental_id), readings);
+}
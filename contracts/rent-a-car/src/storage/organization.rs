@@ -0,0 +1,56 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::organization::{MemberLimit, Organization},
+    types::{error::Error, storage::DataKey},
+};
+
+pub(crate) fn has_organization(env: &Env, org: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Organization(org.clone()))
+}
+
+pub(crate) fn read_organization(env: &Env, org: &Address) -> Result<Organization, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Organization(org.clone()))
+        .ok_or(Error::NotFound)
+}
+
+pub(crate) fn write_organization(env: &Env, org: &Address, organization: &Organization) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Organization(org.clone()), organization);
+}
+
+pub(crate) fn read_treasury_organization(env: &Env, treasury: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TreasuryOrganization(treasury.clone()))
+}
+
+pub(crate) fn write_treasury_organization(env: &Env, treasury: &Address, org: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TreasuryOrganization(treasury.clone()), org);
+}
+
+pub(crate) fn read_org_member(env: &Env, org: &Address, member: &Address) -> Result<MemberLimit, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrgMember(org.clone(), member.clone()))
+        .ok_or(Error::NotFound)
+}
+
+pub(crate) fn write_org_member(env: &Env, org: &Address, member: &Address, limit: &MemberLimit) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::OrgMember(org.clone(), member.clone()), limit);
+}
+
+pub(crate) fn remove_org_member(env: &Env, org: &Address, member: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::OrgMember(org.clone(), member.clone()));
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::Env;
+
+use crate::{
+    constants::{
+        DEFAULT_KEEPER_REWARD_PERCENTAGE, DEFAULT_OVERDUE_GRACE_PERIOD,
+        DEFAULT_OVERDUE_PENALTY_PERCENTAGE,
+    },
+    storage::{structs::overdue_policy::OverduePolicy, types::storage::DataKey},
+};
+
+pub(crate) fn read_overdue_policy(env: &Env) -> OverduePolicy {
+    env.storage()
+        .instance()
+        .get(&DataKey::OverduePolicy)
+        .unwrap_or(OverduePolicy {
+            grace_period: DEFAULT_OVERDUE_GRACE_PERIOD,
+            penalty_percentage: DEFAULT_OVERDUE_PENALTY_PERCENTAGE,
+            keeper_reward_percentage: DEFAULT_KEEPER_REWARD_PERCENTAGE,
+        })
+}
+
+pub(crate) fn write_overdue_policy(env: &Env, policy: &OverduePolicy) {
+    env.storage().instance().set(&DataKey::OverduePolicy, policy);
+}
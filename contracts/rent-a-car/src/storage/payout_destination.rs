@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{structs::payout_destination::PayoutDestination, types::storage::DataKey};
+
+pub(crate) fn read_payout_destination(env: &Env, owner: &Address) -> Option<PayoutDestination> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PayoutDestination(owner.clone()))
+}
+
+pub(crate) fn write_payout_destination(env: &Env, owner: &Address, destination: &PayoutDestination) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PayoutDestination(owner.clone()), destination);
+}
@@ -0,0 +1,26 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{structs::pricing_rules::PricingRules, types::storage::DataKey};
+
+pub(crate) fn read_pricing_rules(env: &Env, owner: &Address) -> PricingRules {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PricingRules(owner.clone()))
+        .unwrap_or(PricingRules {
+            weekend_surcharge: 0,
+            seasons: Vec::new(env),
+            long_stay_discounts: Vec::new(env),
+        })
+}
+
+pub(crate) fn write_pricing_rules(env: &Env, owner: &Address, rules: &PricingRules) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PricingRules(owner.clone()), rules);
+}
+
+pub(crate) fn remove_pricing_rules(env: &Env, owner: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PricingRules(owner.clone()));
+}
\ No newline at end of file
@@ -0,0 +1,31 @@
+use soroban_sdk::{BytesN, Env};
+
+use crate::storage::{
+    structs::promo_code::PromoCode,
+    types::{error::Error, storage::DataKey},
+};
+
+pub(crate) fn has_promo_code(env: &Env, code_hash: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::PromoCode(code_hash.clone()))
+}
+
+pub(crate) fn read_promo_code(env: &Env, code_hash: &BytesN<32>) -> Result<PromoCode, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PromoCode(code_hash.clone()))
+        .ok_or(Error::NotFound)
+}
+
+pub(crate) fn write_promo_code(env: &Env, code_hash: &BytesN<32>, promo_code: &PromoCode) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PromoCode(code_hash.clone()), promo_code);
+}
+
+pub(crate) fn remove_promo_code(env: &Env, code_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PromoCode(code_hash.clone()));
+}
\ No newline at end of file
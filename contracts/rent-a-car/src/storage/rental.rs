@@ -2,24 +2,73 @@ use soroban_sdk::{Address, Env};
 
 use crate::storage::{structs::rental::Rental, types::{error::Error, storage::DataKey}};
 
-pub(crate) fn has_rental(env: &Env, renter: &Address, car_owner: &Address) -> bool {
+/// Reserva el siguiente identificador de alquiler (los ids comienzan en 1)
+pub(crate) fn next_rental_id(env: &Env) -> u64 {
+    let id = env.storage().instance().get(&DataKey::RentalCounter).unwrap_or(0_u64) + 1;
+    env.storage().instance().set(&DataKey::RentalCounter, &id);
+    id
+}
+
+pub(crate) fn write_rental(env: &Env, rental: &Rental) {
+    let key = DataKey::Rental(rental.id);
+    env.storage().persistent().set(&key, rental)
+}
 
-    let key = DataKey::Rental(renter.clone(), car_owner.clone());
-    env.storage().instance().has(&key)
+pub(crate) fn read_rental(env: &Env, rental_id: u64) -> Result<Rental, Error> {
+    let key = DataKey::Rental(rental_id);
+    env.storage().persistent().get(&key).ok_or(Error::RentalNotFound)
+}
 
+pub(crate) fn read_car_active_rental(env: &Env, owner: &Address) -> Result<u64, Error> {
+    let key = DataKey::CarActiveRental(owner.clone());
+    env.storage().persistent().get(&key).ok_or(Error::RentalNotFound)
 }
 
-pub(crate) fn write_rental(env: &Env, renter: &Address, car_owner: &Address, rental: &Rental) {
-    let key = DataKey::Rental(renter.clone(), car_owner.clone());
-    env.storage().instance().set(&key, rental)
+pub(crate) fn write_car_active_rental(env: &Env, owner: &Address, rental_id: u64) {
+    let key = DataKey::CarActiveRental(owner.clone());
+    env.storage().persistent().set(&key, &rental_id)
 }
 
-pub(crate) fn read_rental(env: &Env, renter: &Address, car_owner: &Address) -> Result<Rental, Error> {
-    let key = DataKey::Rental(renter.clone(), car_owner.clone());
-    env.storage().instance().get(&key).ok_or(Error::RentalNotFound)
+pub(crate) fn remove_car_active_rental(env: &Env, owner: &Address) {
+    let key = DataKey::CarActiveRental(owner.clone());
+    env.storage().persistent().remove(&key)
 }
 
-pub(crate) fn remove_rental(env: &Env, renter: &Address, car_owner: &Address) {
-    let key = DataKey::Rental(renter.clone(), car_owner.clone());
-    env.storage().instance().remove(&key)
+pub(crate) fn read_renter_rental_count(env: &Env, renter: &Address) -> u32 {
+    let key = DataKey::RenterRentalCount(renter.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+pub(crate) fn read_renter_rental_id(env: &Env, renter: &Address, index: u32) -> Result<u64, Error> {
+    let key = DataKey::RenterRental(renter.clone(), index);
+    env.storage().persistent().get(&key).ok_or(Error::RentalNotFound)
+}
+
+pub(crate) fn read_car_rental_count(env: &Env, owner: &Address) -> u32 {
+    let key = DataKey::CarRentalCount(owner.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+pub(crate) fn read_car_rental_id(env: &Env, owner: &Address, index: u32) -> Result<u64, Error> {
+    let key = DataKey::CarRental(owner.clone(), index);
+    env.storage().persistent().get(&key).ok_or(Error::RentalNotFound)
+}
+
+/// Agrega el alquiler a los índices renter→alquileres y auto→alquileres
+pub(crate) fn index_rental(env: &Env, rental: &Rental) {
+    let renter_count = read_renter_rental_count(env, &rental.renter);
+    env.storage()
+        .persistent()
+        .set(&DataKey::RenterRental(rental.renter.clone(), renter_count), &rental.id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::RenterRentalCount(rental.renter.clone()), &(renter_count + 1));
+
+    let car_count = read_car_rental_count(env, &rental.owner);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarRental(rental.owner.clone(), car_count), &rental.id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarRentalCount(rental.owner.clone()), &(car_count + 1));
 }
\ No newline at end of file
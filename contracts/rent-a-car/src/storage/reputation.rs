@@ -0,0 +1,28 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{structs::reputation::Reputation, types::storage::DataKey};
+
+pub(crate) fn read_reputation(env: &Env, address: &Address) -> Reputation {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Reputation(address.clone()))
+        .unwrap_or(Reputation { count: 0, sum: 0 })
+}
+
+pub(crate) fn write_reputation(env: &Env, address: &Address, reputation: &Reputation) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Reputation(address.clone()), reputation);
+}
+
+pub(crate) fn has_rated(env: &Env, rental_id: u64, rater: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::RentalRating(rental_id, rater.clone()))
+}
+
+pub(crate) fn write_rated(env: &Env, rental_id: u64, rater: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RentalRating(rental_id, rater.clone()), &true);
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::shares::{CarShares, ShareHolding},
+    types::storage::DataKey,
+};
+
+pub(crate) fn read_car_shares(env: &Env, car: &Address) -> Option<CarShares> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarShares(car.clone()))
+}
+
+pub(crate) fn write_car_shares(env: &Env, car: &Address, shares: &CarShares) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarShares(car.clone()), shares);
+}
+
+pub(crate) fn read_share_holding(env: &Env, car: &Address, holder: &Address) -> ShareHolding {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ShareHolding(car.clone(), holder.clone()))
+        .unwrap_or_default()
+}
+
+pub(crate) fn write_share_holding(env: &Env, car: &Address, holder: &Address, holding: &ShareHolding) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ShareHolding(car.clone(), holder.clone()), holding);
+}
+
+pub(crate) fn read_share_token(env: &Env, car: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ShareToken(car.clone()))
+}
+
+pub(crate) fn write_share_token(env: &Env, car: &Address, token: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ShareToken(car.clone()), token);
+}
@@ -0,0 +1,8 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Attestation {
+    pub verifier: Address,
+    pub expires_at: u64,
+}
\ No newline at end of file
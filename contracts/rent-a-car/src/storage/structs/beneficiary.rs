@@ -0,0 +1,8 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Beneficiary {
+    pub address: Address,
+    pub share_bps: u32,  // participación en puntos básicos (10.000 = 100%)
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype};
+use soroban_sdk::{contracttype, String};
 
 use crate::storage::types::car_status::CarStatus;
 
@@ -8,5 +8,12 @@ pub struct Car {
     pub price_per_day: i128,
     pub car_status: CarStatus,
     pub available_to_withdraw: i128,
-    pub comission_to_admin: i128
+    pub comission_to_admin: i128,
+    pub metadata: String,
+    pub deposit: i128,
+    pub min_renter_rating: u32,  // 0 si el owner no exige una calificación mínima
+    pub requires_verification: bool,
+    pub required_license_class: u32,  // 0 si se acepta cualquier clase de licencia
+    pub included_km_per_day: u32,   // 0 si el kilometraje es libre
+    pub overage_fee_per_km: i128,   // recargo por kilómetro excedente
 }
\ No newline at end of file
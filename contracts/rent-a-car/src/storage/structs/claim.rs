@@ -0,0 +1,11 @@
+use soroban_sdk::{contracttype, BytesN};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Claim {
+    pub amount: i128,
+    pub owner_evidence: BytesN<32>,
+    pub renter_evidence: Option<BytesN<32>>,
+    pub filed_ts: u64,
+    pub deadline: u64,  // pasado este momento el reclamo expira a favor del renter
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use soroban_sdk::{contracttype, BytesN};
+
+/// Credencial firmada fuera de la cadena por un verificador sobre
+/// `(contrato, renter, license_class, expires_at)`
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Credential {
+    pub license_class: u32,
+    pub expires_at: u64,
+    pub public_key: BytesN<32>,
+    pub signature: BytesN<64>,
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use soroban_sdk::{contracttype, BytesN};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct HandoverReport {
+    pub odometer: Option<u64>,        // kilómetros registrados en la entrega
+    pub fuel_level: Option<u32>,      // porcentaje de combustible
+    pub condition_hash: BytesN<32>,   // hash del informe de estado del auto
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Handover {
+    pub report: HandoverReport,
+    pub timestamp: u64,
+}
@@ -0,0 +1,8 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct InsuranceConfig {
+    pub premium_percentage: u32,  // porcentaje del monto del alquiler que paga el renter
+    pub coverage_limit: i128,     // máximo que el fondo cubre por reclamo
+}
\ No newline at end of file
@@ -1,2 +1,18 @@
 pub mod car;
-pub mod rental;
\ No newline at end of file
+pub mod rental;
+pub mod pricing_rules;
+pub mod promo_code;
+pub mod claim;
+pub mod insurance;
+pub mod reputation;
+pub mod attestation;
+pub mod credential;
+pub mod rental_options;
+pub mod organization;
+pub mod overdue_policy;
+pub mod handover;
+pub mod odometer;
+pub mod telematics;
+pub mod payout_destination;
+pub mod beneficiary;
+pub mod shares;
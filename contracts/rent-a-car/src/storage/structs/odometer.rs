@@ -0,0 +1,8 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct OdometerReadings {
+    pub start: Option<u64>,  // kilómetros al retirar el auto
+    pub end: Option<u64>,    // kilómetros al devolverlo (o última lectura del reporter si el reporte no los trae)
+}
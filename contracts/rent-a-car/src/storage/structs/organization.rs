@@ -0,0 +1,23 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+pub enum SpendingPeriod {
+    Daily,
+    Monthly,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Organization {
+    pub treasury: Address,  // cuenta que aprueba la allowance y paga los alquileres
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct MemberLimit {
+    pub limit: i128,             // gasto máximo por período
+    pub period: SpendingPeriod,
+    pub spent: i128,             // gastado en el período en curso
+    pub period_start: u64,       // inicio del período en curso
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct OverduePolicy {
+    pub grace_period: u64,              // segundos de tolerancia después de `end_ts`
+    pub penalty_percentage: u32,        // recargo por día de atraso sobre el precio diario
+    pub keeper_reward_percentage: u32,  // parte de la penalidad que cobra quien liquida
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PayoutDestination {
+    pub destination: Address,      // destino vigente de los pagos
+    pub pending: Option<Address>,  // cambio solicitado que aún no cumplió el timelock
+    pub effective_at: u64,         // momento a partir del cual rige el cambio pendiente
+}
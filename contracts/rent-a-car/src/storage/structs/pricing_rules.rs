@@ -0,0 +1,26 @@
+use soroban_sdk::{contracttype, Vec};
+
+/// Multiplicador de temporada (porcentaje, 100 = tarifa base) aplicado entre `start_ts` y `end_ts`
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SeasonalRate {
+    pub start_ts: u64,
+    pub end_ts: u64,
+    pub multiplier: u32,
+}
+
+/// Descuento (porcentaje) para alquileres de al menos `min_days` días
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LongStayDiscount {
+    pub min_days: u32,
+    pub discount: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PricingRules {
+    pub weekend_surcharge: u32,  // Recargo (porcentaje) para sábados y domingos
+    pub seasons: Vec<SeasonalRate>,
+    pub long_stay_discounts: Vec<LongStayDiscount>,
+}
\ No newline at end of file
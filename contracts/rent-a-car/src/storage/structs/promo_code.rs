@@ -0,0 +1,18 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Discount {
+    Percentage(u32),  // porcentaje sobre el precio del alquiler
+    Fixed(i128),      // monto fijo en unidades del token
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PromoCode {
+    pub discount: Discount,
+    pub expires_at: u64,
+    pub max_uses: u32,
+    pub uses: u32,
+    pub car: Option<Address>,  // si está definido, el código solo aplica a ese auto
+}
\ No newline at end of file
@@ -1,9 +1,33 @@
-use soroban_sdk::{contracttype};
+use soroban_sdk::{contracttype, Address};
 
-#[derive(Clone)]
+use crate::storage::types::rental_status::RentalStatus;
+
+/// Origen de los fondos con los que se pagan un alquiler y sus extensiones
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum PaymentSource {
+    Renter,                 // el renter paga directamente y autoriza la transferencia
+    Sponsor(Address),       // un sponsor gasta la allowance que le otorgó el renter
+    Organization(Address),  // la tesorería de la empresa paga con la allowance otorgada al contrato
+}
+
+#[derive(Clone, Debug, PartialEq)]
 #[contracttype]
 pub struct Rental {
+    pub id: u64,
+    pub renter: Address,
+    pub payer: Address,    // Dirección que pagó el alquiler y recibe los reembolsos
+    pub payment: PaymentSource,
+    pub owner: Address,
+    pub start_ts: u64,
+    pub end_ts: u64,
     pub total_days_to_rent: u32,
     pub amount: i128,
+    pub released: i128,    // Parte del monto ya acreditada al saldo retirable del owner
     pub commission: i128,  // Comisión aplicada al alquiler
+    pub deposit: i128,     // Depósito de garantía retenido hasta liquidar el alquiler
+    pub insurance_premium: i128,  // Prima pagada al fondo de seguros (0 si no está asegurado)
+    pub overdue_ts: Option<u64>,  // Momento en que se marcó como vencido y se cobró la penalidad por atraso
+    pub status: RentalStatus,
+    pub returned_ts: Option<u64>,
 }
\ No newline at end of file
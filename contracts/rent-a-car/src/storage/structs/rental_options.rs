@@ -0,0 +1,9 @@
+use soroban_sdk::{contracttype, Bytes};
+
+/// Opciones adicionales que el renter puede indicar al alquilar
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct RentalOptions {
+    pub promo_code: Option<Bytes>,
+    pub insured: bool,
+}
\ No newline at end of file
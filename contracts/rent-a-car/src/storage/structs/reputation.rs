@@ -0,0 +1,8 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Reputation {
+    pub count: u32,  // cantidad de calificaciones recibidas
+    pub sum: u32,    // suma de los puntajes (1 a 5)
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarShares {
+    pub token: Address,           // token SEP-41 que lleva los saldos de participaciones
+    pub revenue_per_share: i128,  // ingreso acumulado por participación, escalado por SHARE_REVENUE_PRECISION
+    pub dust: i128,               // resto escalado que no alcanzó para repartir y se suma al próximo ingreso
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct ShareHolding {
+    pub revenue_per_share_paid: i128,  // valor del acumulador en la última liquidación
+    pub owed: i128,                    // ingresos liquidados pendientes de cobro
+}
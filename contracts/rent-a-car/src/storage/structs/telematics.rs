@@ -0,0 +1,17 @@
+use soroban_sdk::{contracttype, Vec};
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TelematicsStatus {
+    pub location_zone: u32,
+    pub odometer: u64,
+    pub fuel_level: u32,       // porcentaje de combustible
+    pub fault_codes: Vec<u32>, // códigos de falla activos; vacío si no hay fallas
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TelematicsSnapshot {
+    pub status: TelematicsStatus,
+    pub timestamp: u64,
+}
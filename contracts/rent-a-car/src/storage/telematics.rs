@@ -0,0 +1,38 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::telematics::TelematicsSnapshot,
+    types::{error::Error, storage::DataKey},
+};
+
+pub(crate) fn read_telematics_reporter(env: &Env, owner: &Address) -> Result<Address, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TelematicsReporter(owner.clone()))
+        .ok_or(Error::NotFound)
+}
+
+pub(crate) fn write_telematics_reporter(env: &Env, owner: &Address, reporter: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TelematicsReporter(owner.clone()), reporter);
+}
+
+pub(crate) fn remove_telematics_reporter(env: &Env, owner: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TelematicsReporter(owner.clone()));
+}
+
+pub(crate) fn read_telematics_snapshot(env: &Env, owner: &Address) -> Result<TelematicsSnapshot, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TelematicsSnapshot(owner.clone()))
+        .ok_or(Error::NotFound)
+}
+
+pub(crate) fn write_telematics_snapshot(env: &Env, owner: &Address, snapshot: &TelematicsSnapshot) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TelematicsSnapshot(owner.clone()), snapshot);
+}
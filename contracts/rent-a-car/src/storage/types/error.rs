@@ -22,4 +22,25 @@ pub enum Error {
     CommissionNotSet = 15,
     CarNotRented = 16,  // Error cuando se intenta devolver un auto que no está rentado
     CommissionTooHigh = 17,
+    ListingNotPending = 18,
+    Unauthorized = 19,
+    ListingNotApproved = 20,
+    InvalidParameters = 21,
+    PaymentBelowQuote = 22,
+    AlreadyExists = 23,
+    NotFound = 24,
+    PromoCodeExpired = 25,
+    PromoCodeExhausted = 26,
+    PromoCodeNotApplicable = 27,
+    InvalidRentalTransition = 28,
+    DeadlinePassed = 29,
+    DeadlineNotReached = 30,
+    RentalNotReturned = 31,
+    RenterRatingTooLow = 32,
+    RenterNotVerified = 33,
+    AddressBlocked = 34,
+    DriverLimitReached = 35,
+    RentalNotActive = 36,
+    SpendingLimitExceeded = 37,
+    ConditionUnchanged = 38,
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
 pub mod storage;
 pub mod car_status;
-pub mod error;
\ No newline at end of file
+pub mod error;
+pub mod rental_status;
\ No newline at end of file
@@ -0,0 +1,14 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum RentalStatus {
+    Reserved,
+    Active,
+    Returned,
+    Cancelled,
+    Overdue,
+    Disputed,
+    Settled,
+}
\ No newline at end of file
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN};
 
 #[derive(Clone)]
 #[contracttype]
@@ -7,7 +7,46 @@ pub enum DataKey {
     Token,
     ContractBalance,               // dirección del token de pago aceptado
     Car(Address),                  // auto asociado a un owner
-    Rental(Address, Address),      // registro de alquiler entre renter y owner
+    CarToken(Address),             // id del NFT que representa a un auto
+    CarTokenCounter,               // último id de NFT asignado
+    TokenCar(u32),                 // auto representado por un NFT
+    Rental(u64),                   // registro de alquiler por id
+    RentalCounter,                 // último id de alquiler asignado
+    RenterRentalCount(Address),    // cantidad de alquileres de un renter
+    RenterRental(Address, u32),    // índice renter → id de alquiler
+    CarRentalCount(Address),       // cantidad de alquileres de un auto
+    CarRental(Address, u32),       // índice auto → id de alquiler
+    CarActiveRental(Address),      // alquiler en curso de un auto
+    ShareToken(Address),           // token SEP-41 de participaciones desplegado para un auto
+    CarShares(Address),            // participaciones emitidas sobre un auto y su acumulador de ingresos
+    ShareHolding(Address, Address), // ingresos pendientes de un titular de participaciones de un auto
+    Pickup(u64),                   // informe de entrega del auto firmado por ambas partes
+    Return(u64),                   // informe de devolución del auto firmado por ambas partes
+    Odometer(u64),                 // lecturas del odómetro al inicio y al final de un alquiler
+    TelematicsReporter(Address),   // dispositivo de telemetría confiable de un auto
+    TelematicsSnapshot(Address),   // último estado informado por la telemetría de un auto
+    Arbiter,                       // árbitro que resuelve los reclamos por daños
+    Claim(u64),                    // reclamo por daños asociado a un alquiler
+    InsuranceConfig,               // prima y cobertura del seguro opcional
+    InsurancePoolBalance,          // fondos disponibles en el fondo de seguros
+    OverduePolicy,                 // tolerancia, penalidad y recompensa para alquileres vencidos
+    Reputation(Address),           // calificaciones acumuladas de una dirección
+    RentalRating(u64, Address),    // marca que una parte ya calificó un alquiler
+    Verifier(Address),             // verificadores de licencias de conducir habilitados
+    Attestation(Address),          // verificación vigente de un renter
+    VerifierKey(BytesN<32>),       // claves ed25519 de verificadores para credenciales firmadas
+    Blocked(Address),              // direcciones bloqueadas por fraude y el motivo
+    RentalDrivers(u64),            // conductores adicionales autorizados en un alquiler
+    Organization(Address),         // empresa con su tesorería
+    TreasuryOrganization(Address), // empresa a la que está vinculada una tesorería
+    OrgMember(Address, Address),   // límite de gasto de un miembro de una empresa
     AdminCommission,               // comisión base configurada por el admin
     AdminAccumulatedCommission,    // comisiones acumuladas disponibles para el admin
+    AutoPayout(Address),           // owners con pago automático activado
+    Beneficiaries(Address),        // copropietarios de un auto y su participación
+    BeneficiaryBalance(Address, Address), // saldo retirable de un copropietario en un auto
+    PayoutDestination(Address),    // destino de los pagos de un owner, con cambios sujetos a timelock
+    FleetManager(Address),         // gestores de flota autorizados a aprobar publicaciones
+    PricingRules(Address),         // reglas de precio dinámico de un auto
+    PromoCode(BytesN<32>),         // código promocional indexado por su hash sha256
 }
\ No newline at end of file
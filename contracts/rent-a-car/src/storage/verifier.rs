@@ -0,0 +1,27 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::storage::types::storage::DataKey;
+
+pub(crate) fn is_verifier(env: &Env, verifier: &Address) -> bool {
+    env.storage().instance().has(&DataKey::Verifier(verifier.clone()))
+}
+
+pub(crate) fn write_verifier(env: &Env, verifier: &Address) {
+    env.storage().instance().set(&DataKey::Verifier(verifier.clone()), &true);
+}
+
+pub(crate) fn remove_verifier(env: &Env, verifier: &Address) {
+    env.storage().instance().remove(&DataKey::Verifier(verifier.clone()));
+}
+
+pub(crate) fn is_verifier_key(env: &Env, public_key: &BytesN<32>) -> bool {
+    env.storage().instance().has(&DataKey::VerifierKey(public_key.clone()))
+}
+
+pub(crate) fn write_verifier_key(env: &Env, public_key: &BytesN<32>) {
+    env.storage().instance().set(&DataKey::VerifierKey(public_key.clone()), &true);
+}
+
+pub(crate) fn remove_verifier_key(env: &Env, public_key: &BytesN<32>) {
+    env.storage().instance().remove(&DataKey::VerifierKey(public_key.clone()));
+}
\ No newline at end of file
@@ -1,5 +1,5 @@
 use crate::{
-    storage::{car::read_car, comission, types::car_status::CarStatus},
+    storage::{car::read_car, types::car_status::CarStatus},
     tests::config::{contract::ContractTest, utils::get_contract_events},
 };
 use soroban_sdk::{
@@ -25,7 +25,7 @@ pub fn test_add_car_successfully() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "add_car",
-                args: (owner.clone(), price_per_day).into_val(&env),
+                args: (owner.clone(), price_per_day, comission).into_val(&env),
                 sub_invokes: &[],
             },
         }])
@@ -47,6 +47,15 @@ let contract_events = get_contract_events(&env, &contract.address);
                     owner.clone().into_val(&env),
                 ],
                 price_per_day.into_val(&env)
+            ),
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "mint").as_val(),
+                    owner.clone().into_val(&env),
+                ],
+                1_u32.into_val(&env)
             )
         ]
     );
@@ -67,7 +76,7 @@ pub fn test_unauthorized_user_cannot_add_car() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "add_car",
-                args: (owner.clone(), price_per_day).into_val(&env),
+                args: (owner.clone(), price_per_day, comission).into_val(&env),
                 sub_invokes: &[],
             },
         }])
@@ -0,0 +1,77 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{
+    storage::{car::read_car, structs::rental_options::RentalOptions},
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+#[test]
+pub fn test_auto_payout_on_return() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let cold_wallet = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_payout_destination(&owner, &cold_wallet);
+    contract.set_auto_payout(&owner, &true);
+    assert!(contract.get_auto_payout(&owner));
+
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    assert_eq!(token_client.balance(&cold_wallet), 4500);
+    assert_eq!(token_client.balance(&owner), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+pub fn test_auto_payout_leaves_nothing_to_withdraw() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_auto_payout(&owner, &true);
+
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
+
+    contract.payout_owner(&owner, &1, &None);
+}
+
+#[test]
+pub fn test_auto_payout_falls_back_to_accrual() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let frozen_wallet = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+    token_admin.mint(&frozen_wallet, &1);
+    token_admin.set_authorized(&frozen_wallet, &false);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_payout_destination(&owner, &frozen_wallet);
+    contract.set_auto_payout(&owner, &true);
+
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
+
+    // La transferencia al destino bloqueado falla y el monto queda disponible para retirar
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    assert_eq!(car.available_to_withdraw, 4500);
+    assert_eq!(token_client.balance(&frozen_wallet), 1);
+}
@@ -0,0 +1,154 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
+use crate::{
+    storage::{car::read_car, structs::{beneficiary::Beneficiary, rental_options::RentalOptions}},
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+fn split(env: &Env, shares: &[(&Address, u32)]) -> Vec<Beneficiary> {
+    let mut beneficiaries = Vec::new(env);
+    for (address, share_bps) in shares {
+        beneficiaries.push_back(Beneficiary { address: (*address).clone(), share_bps: *share_bps });
+    }
+    beneficiaries
+}
+
+#[test]
+pub fn test_revenue_split_between_beneficiaries() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_beneficiaries(&owner, &split(&env, &[(&owner, 7_000), (&partner, 3_000)]));
+
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    assert_eq!(contract.get_beneficiary_balance(&owner, &owner), 3150);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &partner), 1350);
+
+    // El saldo del auto no recibe el ingreso repartido
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    assert_eq!(car.available_to_withdraw, 0);
+
+    contract.payout_beneficiary(&owner, &partner, &1350);
+    assert_eq!(token_client.balance(&partner), 1350);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &partner), 0);
+}
+
+#[test]
+pub fn test_split_rounding_goes_to_first_beneficiary() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1000, &10);
+    contract.set_beneficiaries(&owner, &split(&env, &[(&a, 3_334), (&b, 3_333), (&c, 3_333)]));
+
+    let rental_id = contract.rental(&renter, &owner, &1, &1000, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    assert_eq!(contract.get_beneficiary_balance(&owner, &a), 334);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &b), 333);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &c), 333);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+pub fn test_shares_must_sum_to_total() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_beneficiaries(&owner, &split(&env, &[(&owner, 5_000), (&partner, 4_000)]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+pub fn test_duplicate_beneficiary_rejected() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_beneficiaries(&owner, &split(&env, &[(&owner, 5_000), (&owner, 5_000)]));
+}
+
+#[test]
+pub fn test_changing_split_requires_all_beneficiaries() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_beneficiaries(&owner, &split(&env, &[(&owner, 5_000), (&partner, 5_000)]));
+    contract.set_beneficiaries(&owner, &vec![&env, Beneficiary { address: owner.clone(), share_bps: 10_000 }]);
+
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == owner));
+    assert!(auths.iter().any(|(address, _)| *address == partner));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+pub fn test_payout_beneficiary_above_balance() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_beneficiaries(&owner, &split(&env, &[(&owner, 5_000), (&partner, 5_000)]));
+
+    contract.payout_beneficiary(&owner, &partner, &1);
+}
+
+#[test]
+pub fn test_damage_award_is_split_between_beneficiaries() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &0);
+    contract.set_car_deposit(&owner, &2000);
+    contract.set_beneficiaries(&owner, &split(&env, &[(&owner, 5_000), (&partner, 5_000)]));
+    contract.set_arbiter(&Address::generate(&env));
+
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 2));
+    contract.file_claim(&rental_id, &1000, &handover_report(&env, 9).condition_hash);
+    contract.resolve_claim(&rental_id, &1000);
+
+    assert_eq!(contract.get_beneficiary_balance(&owner, &owner), 2250 + 500);
+    assert_eq!(contract.get_beneficiary_balance(&owner, &partner), 2250 + 500);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    assert_eq!(car.available_to_withdraw, 0);
+}
@@ -0,0 +1,59 @@
+use soroban_sdk::{testutils::Address as _, Address, String};
+use crate::{storage::structs::rental_options::RentalOptions, tests::config::{contract::ContractTest, utils::handover_report}};
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+pub fn test_blocked_renter_cannot_rent() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.block_address(&renter, &String::from_str(&env, "Pago fraudulento"));
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+pub fn test_blocked_owner_cannot_receive_rentals() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.block_address(&owner, &String::from_str(&env, "Auto inexistente"));
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+}
+
+#[test]
+pub fn test_blocked_owner_can_still_withdraw_earnings() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
+
+    contract.block_address(&owner, &String::from_str(&env, "Auto inexistente"));
+    assert!(contract.is_blocked(&owner));
+
+    contract.payout_owner(&owner, &4500, &None);
+    assert_eq!(token_client.balance(&owner), 4500);
+
+    contract.unblock_address(&owner);
+    assert!(!contract.is_blocked(&owner));
+}
\ No newline at end of file
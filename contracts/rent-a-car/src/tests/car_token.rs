@@ -0,0 +1,399 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, String, Vec};
+use crate::{
+    constants::PAYOUT_DESTINATION_TIMELOCK,
+    storage::{car::read_car, structs::{beneficiary::Beneficiary, rental_options::RentalOptions}},
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+#[test]
+pub fn test_car_token_minted_on_listing() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let lister = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.request_listing(&lister, &String::from_str(&env, "Fiat 600"), &1000);
+
+    let token_id = contract.get_car_token(&owner);
+    assert_eq!(contract.owner_of(&token_id), owner);
+    assert_eq!(contract.balance(&owner), 1);
+
+    // La publicación pendiente no tiene NFT hasta ser aprobada
+    assert!(contract.try_get_car_token(&lister).is_err());
+    contract.approve_listing(&admin, &lister, &5);
+    assert_eq!(contract.owner_of(&contract.get_car_token(&lister)), lister);
+}
+
+#[test]
+pub fn test_payouts_follow_token_holder() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &20_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    // Lo ganado antes de la venta se le paga al vendedor al transferir el NFT
+    let token_id = contract.get_car_token(&owner);
+    contract.transfer(&owner, &buyer, &token_id);
+
+    assert_eq!(contract.owner_of(&token_id), buyer);
+    assert_eq!(contract.balance(&owner), 0);
+    assert_eq!(contract.balance(&buyer), 1);
+    assert_eq!(token_client.balance(&owner), 4500);
+
+    // Las ganancias futuras son del comprador
+    let rental_id = contract.rental(&renter, &owner, &2, &3000, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    contract.payout_owner(&owner, &3000, &None);
+
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == buyer));
+    assert!(!auths.iter().any(|(address, _)| *address == owner));
+
+    assert_eq!(token_client.balance(&buyer), 3000);
+    assert_eq!(token_client.balance(&owner), 4500);
+}
+
+#[test]
+pub fn test_accrued_balance_paid_to_seller_destination() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let cold_wallet = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_payout_destination(&owner, &cold_wallet);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+
+    assert_eq!(token_client.balance(&cold_wallet), 4500);
+    assert!(contract.try_payout_owner(&owner, &1, &None).is_err());
+}
+
+#[test]
+pub fn test_destination_after_transfer_waits_for_timelock() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let buyer_wallet = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+    contract.set_payout_destination(&owner, &buyer_wallet);
+
+    assert_eq!(contract.get_payout_destination(&owner), buyer);
+
+    env.ledger().with_mut(|li| li.timestamp += PAYOUT_DESTINATION_TIMELOCK);
+    assert_eq!(contract.get_payout_destination(&owner), buyer_wallet);
+}
+
+#[test]
+pub fn test_transfer_resets_payout_destination() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let cold_wallet = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_payout_destination(&owner, &cold_wallet);
+    contract.set_auto_payout(&owner, &true);
+
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+
+    assert_eq!(contract.get_payout_destination(&owner), buyer);
+    assert!(!contract.get_auto_payout(&owner));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+pub fn test_transfer_blocked_while_rented() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_transfer_requires_current_holder() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    let token_id = contract.get_car_token(&owner);
+    contract.transfer(&owner, &buyer, &token_id);
+
+    contract.transfer(&owner, &buyer, &token_id);
+}
+
+#[test]
+pub fn test_approved_address_transfers_token() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    let token_id = contract.get_car_token(&owner);
+    contract.approve(&owner, &marketplace, &token_id, &(env.ledger().sequence() + 100));
+    assert_eq!(contract.get_approved(&token_id), Some(marketplace.clone()));
+
+    contract.transfer_from(&marketplace, &owner, &buyer, &token_id);
+
+    assert_eq!(contract.owner_of(&token_id), buyer);
+    assert_eq!(contract.get_payout_destination(&owner), buyer);
+
+    // La aprobación no pasa al nuevo titular
+    assert_eq!(contract.get_approved(&token_id), None);
+}
+
+#[test]
+pub fn test_operator_transfers_token() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    let token_id = contract.get_car_token(&owner);
+    contract.approve_for_all(&owner, &operator, &(env.ledger().sequence() + 100));
+    assert!(contract.is_approved_for_all(&owner, &operator));
+
+    // Un operador también puede aprobar a terceros sobre los NFTs del titular
+    contract.approve(&operator, &marketplace, &token_id, &(env.ledger().sequence() + 100));
+    assert_eq!(contract.get_approved(&token_id), Some(marketplace));
+
+    contract.transfer_from(&operator, &owner, &buyer, &token_id);
+    assert_eq!(contract.owner_of(&token_id), buyer);
+
+    contract.approve_for_all(&owner, &operator, &0);
+    assert!(!contract.is_approved_for_all(&owner, &operator));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_transfer_from_requires_approval() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    let token_id = contract.get_car_token(&owner);
+
+    contract.transfer_from(&marketplace, &owner, &marketplace, &token_id);
+}
+
+#[test]
+pub fn test_approval_expires() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    let token_id = contract.get_car_token(&owner);
+    contract.approve(&owner, &marketplace, &token_id, &(env.ledger().sequence() + 10));
+
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 11);
+
+    assert_eq!(contract.get_approved(&token_id), None);
+    assert!(contract.try_transfer_from(&marketplace, &owner, &marketplace, &token_id).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_only_holder_approves() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    let token_id = contract.get_car_token(&owner);
+
+    contract.approve(&stranger, &stranger, &token_id, &(env.ledger().sequence() + 100));
+}
+
+#[test]
+pub fn test_car_token_metadata() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let lister = Address::generate(&env);
+
+    contract.request_listing(&lister, &String::from_str(&env, "Fiat 600"), &1000);
+    contract.approve_listing(&admin, &lister, &5);
+
+    assert_eq!(contract.name(), String::from_str(&env, "Rent a Car"));
+    assert_eq!(contract.symbol(), String::from_str(&env, "CAR"));
+    assert_eq!(
+        contract.token_uri(&contract.get_car_token(&lister)),
+        String::from_str(&env, "Fiat 600")
+    );
+}
+
+#[test]
+pub fn test_car_token_burned_on_removal() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    let token_id = contract.get_car_token(&owner);
+    contract.remove_car(&owner);
+
+    assert!(contract.try_owner_of(&token_id).is_err());
+    assert_eq!(contract.balance(&owner), 0);
+    assert!(env.as_contract(&contract.address, || read_car(&env, &owner)).is_err());
+}
+
+#[test]
+pub fn test_transfer_requires_dissolved_split() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_beneficiaries(&owner, &vec![
+        &env,
+        Beneficiary { address: owner.clone(), share_bps: 5_000 },
+        Beneficiary { address: partner.clone(), share_bps: 5_000 },
+    ]);
+
+    let token_id = contract.get_car_token(&owner);
+    assert!(contract.try_transfer(&owner, &buyer, &token_id).is_err());
+
+    contract.set_beneficiaries(&owner, &Vec::new(&env));
+    contract.transfer(&owner, &buyer, &token_id);
+    assert_eq!(contract.owner_of(&token_id), buyer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+pub fn test_blocked_buyer_cannot_receive_rentals() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+    contract.block_address(&buyer, &String::from_str(&env, "fraude"));
+
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+}
+
+#[test]
+pub fn test_clean_buyer_of_blocked_lister_can_rent_out() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+    contract.block_address(&owner, &String::from_str(&env, "fraude"));
+
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+pub fn test_holder_cannot_rent_own_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&buyer, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+
+    contract.rental(&buyer, &owner, &3, &4500, &RentalOptions::default(), &None);
+}
+
+#[test]
+pub fn test_holder_rates_renter() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.transfer(&owner, &buyer, &contract.get_car_token(&owner));
+
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    let review = handover_report(&env, 5).condition_hash;
+    contract.rate_rental(&rental_id, &buyer, &4, &review);
+    contract.rate_rental(&rental_id, &renter, &5, &review);
+
+    assert_eq!(contract.get_reputation(&renter).sum, 4);
+    assert_eq!(contract.get_reputation(&buyer).sum, 5);
+    assert!(contract.try_rate_rental(&rental_id, &owner, &1, &review).is_err());
+}
@@ -0,0 +1,120 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, BytesN, Env};
+use crate::{
+    constants::{CLAIM_RESOLUTION_PERIOD, CLAIM_WINDOW},
+    storage::{car::read_car, structs::rental_options::RentalOptions, types::rental_status::RentalStatus},
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+const DEPOSIT: i128 = 2000;
+
+fn setup_returned_rental<'a>(test: &ContractTest<'a>) -> (Address, Address, u64) {
+    let env = &test.env;
+    env.mock_all_auths();
+
+    let owner = Address::generate(env);
+    let renter = Address::generate(env);
+    test.token.1.mint(&renter, &10_000);
+
+    test.contract.add_car(&owner, &1500, &0);
+    test.contract.set_car_deposit(&owner, &DEPOSIT);
+    let rental_id = test.contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    test.contract.confirm_pickup(&rental_id, &handover_report(env, 1));
+    test.contract.confirm_return(&rental_id, &handover_report(env, 2));
+
+    (owner, renter, rental_id)
+}
+
+fn evidence(env: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[byte; 32])
+}
+
+#[test]
+pub fn test_rental_holds_deposit() {
+    let test = ContractTest::setup();
+    let (_, renter, rental_id) = setup_returned_rental(&test);
+
+    assert_eq!(test.contract.get_rental(&rental_id).deposit, DEPOSIT);
+    assert_eq!(test.token.0.balance(&renter), 10_000 - 4500 - DEPOSIT);
+}
+
+#[test]
+pub fn test_arbiter_splits_deposit() {
+    let test = ContractTest::setup();
+    let (owner, renter, rental_id) = setup_returned_rental(&test);
+    let ContractTest { env, contract, token, .. } = &test;
+
+    let arbiter = Address::generate(env);
+    contract.set_arbiter(&arbiter);
+
+    contract.file_claim(&rental_id, &1500, &evidence(env, 1));
+    assert_eq!(contract.get_rental(&rental_id).status, RentalStatus::Disputed);
+
+    contract.respond_claim(&rental_id, &evidence(env, 2));
+    assert_eq!(contract.get_claim(&rental_id).renter_evidence, Some(evidence(env, 2)));
+
+    contract.resolve_claim(&rental_id, &500);
+
+    let car = env.as_contract(&contract.address, || read_car(env, &owner)).unwrap();
+    assert_eq!(car.available_to_withdraw, 4500 + 500);
+    assert_eq!(token.0.balance(&renter), 10_000 - 4500 - 500);
+    assert_eq!(contract.get_rental(&rental_id).status, RentalStatus::Settled);
+}
+
+#[test]
+pub fn test_unresolved_claim_expires_in_renter_favor() {
+    let test = ContractTest::setup();
+    let (_, renter, rental_id) = setup_returned_rental(&test);
+    let ContractTest { env, contract, token, .. } = &test;
+
+    contract.file_claim(&rental_id, &1500, &evidence(env, 1));
+
+    env.ledger().set_timestamp(CLAIM_RESOLUTION_PERIOD + 1);
+    contract.expire_claim(&rental_id);
+
+    assert_eq!(token.0.balance(&renter), 10_000 - 4500);
+    assert_eq!(contract.get_rental(&rental_id).status, RentalStatus::Settled);
+}
+
+#[test]
+pub fn test_deposit_released_after_claim_window() {
+    let test = ContractTest::setup();
+    let (_, renter, rental_id) = setup_returned_rental(&test);
+    let ContractTest { env, contract, token, .. } = &test;
+
+    env.ledger().set_timestamp(CLAIM_WINDOW + 1);
+    contract.release_deposit(&rental_id);
+
+    assert_eq!(token.0.balance(&renter), 10_000 - 4500);
+    assert_eq!(contract.get_rental(&rental_id).status, RentalStatus::Settled);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+pub fn test_deposit_cannot_be_released_during_claim_window() {
+    let test = ContractTest::setup();
+    let (_, _, rental_id) = setup_returned_rental(&test);
+
+    test.contract.release_deposit(&rental_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+pub fn test_claim_after_window_fails() {
+    let test = ContractTest::setup();
+    let (_, _, rental_id) = setup_returned_rental(&test);
+
+    test.env.ledger().set_timestamp(CLAIM_WINDOW + 1);
+    test.contract.file_claim(&rental_id, &1500, &evidence(&test.env, 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+pub fn test_arbiter_cannot_award_more_than_deposit() {
+    let test = ContractTest::setup();
+    let (_, _, rental_id) = setup_returned_rental(&test);
+    let ContractTest { env, contract, .. } = &test;
+
+    contract.set_arbiter(&Address::generate(env));
+    contract.file_claim(&rental_id, &5000, &evidence(env, 1));
+    contract.resolve_claim(&rental_id, &(DEPOSIT + 1));
+}
\ No newline at end of file
@@ -1,4 +1,10 @@
-use soroban_sdk::{testutils::Events, token, vec, Address, Env, Val, Vec};
+use car_shares::{CarShareToken, CarShareTokenClient};
+use soroban_sdk::{
+    testutils::{Events, IssuerFlags},
+    token, Address, BytesN, Env, String, Val, Vec,
+};
+
+use crate::{contract::RentACarContractClient, storage::structs::handover::HandoverReport};
 
 pub(crate) fn create_token_contract<'a>(
     e: &Env,
@@ -6,12 +12,34 @@ pub(crate) fn create_token_contract<'a>(
 ) -> (token::Client<'a>, token::StellarAssetClient<'a>) {
     let addr = e.register_stellar_asset_contract_v2(admin.clone());
 
+    // Permite desautorizar cuentas para simular transferencias que fallan
+    addr.issuer().set_flag(IssuerFlags::RevocableFlag);
+
     (
         token::Client::new(e, &addr.address()),
         token::StellarAssetClient::new(e, &addr.address()),
     )
 }
 
+/// Despliega el token de participaciones de un auto y lo registra en el contrato
+pub(crate) fn create_share_token<'a>(
+    env: &Env,
+    contract: &RentACarContractClient,
+    owner: &Address,
+) -> CarShareTokenClient<'a> {
+    let token = env.register(
+        CarShareToken,
+        (
+            &contract.address,
+            owner,
+            String::from_str(env, "Car shares"),
+            String::from_str(env, "SHARE"),
+        ),
+    );
+    contract.register_share_token(owner, &token);
+
+    CarShareTokenClient::new(env, &token)
+}
 
 pub(crate) fn get_contract_events(
     env: &Env,
@@ -26,4 +54,12 @@ pub(crate) fn get_contract_events(
         .for_each(|event| contract_events.push_back(event));
 
     contract_events
-}
\ No newline at end of file
+}
+
+pub(crate) fn handover_report(env: &Env, condition: u8) -> HandoverReport {
+    HandoverReport {
+        odometer: None,
+        fuel_level: None,
+        condition_hash: BytesN::from_array(env, &[condition; 32]),
+    }
+}
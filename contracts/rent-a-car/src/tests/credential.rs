@@ -0,0 +1,151 @@
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use rand::rngs::OsRng;
+use soroban_sdk::{testutils::Address as _, xdr::ToXdr, Address, BytesN, Env};
+use crate::{
+    storage::structs::{credential::Credential, rental_options::RentalOptions},
+    tests::config::contract::ContractTest,
+};
+
+fn sign_credential(
+    env: &Env,
+    signing_key: &SigningKey,
+    contract: &Address,
+    renter: &Address,
+    license_class: u32,
+    expires_at: u64,
+) -> Credential {
+    let message = (contract.clone(), renter.clone(), license_class, expires_at).to_xdr(env);
+    let message: std::vec::Vec<u8> = message.iter().collect();
+
+    Credential {
+        license_class,
+        expires_at,
+        public_key: BytesN::from_array(env, &signing_key.verifying_key().to_bytes()),
+        signature: BytesN::from_array(env, &signing_key.sign(&message).to_bytes()),
+    }
+}
+
+#[test]
+pub fn test_rental_with_signed_credential() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let signing_key = SigningKey::generate(&mut OsRng);
+    let credential = sign_credential(&env, &signing_key, &contract.address, &renter, 2, 1000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_requires_verification(&owner, &true);
+    contract.add_verifier_key(&credential.public_key);
+
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &Some(credential));
+}
+
+#[test]
+#[should_panic]
+pub fn test_credential_signed_for_other_renter_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let signing_key = SigningKey::generate(&mut OsRng);
+    let credential = sign_credential(&env, &signing_key, &contract.address, &Address::generate(&env), 2, 1000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_requires_verification(&owner, &true);
+    contract.add_verifier_key(&credential.public_key);
+
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &Some(credential));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_credential_from_unregistered_key_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let signing_key = SigningKey::generate(&mut OsRng);
+    let credential = sign_credential(&env, &signing_key, &contract.address, &renter, 2, 1000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_requires_verification(&owner, &true);
+
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &Some(credential));
+}
+
+#[test]
+#[should_panic]
+pub fn test_credential_signed_for_other_contract_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let signing_key = SigningKey::generate(&mut OsRng);
+    let credential = sign_credential(&env, &signing_key, &Address::generate(&env), &renter, 2, 1000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_requires_verification(&owner, &true);
+    contract.add_verifier_key(&credential.public_key);
+
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &Some(credential));
+}
+
+#[test]
+pub fn test_credential_with_required_license_class() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let signing_key = SigningKey::generate(&mut OsRng);
+    let credential = sign_credential(&env, &signing_key, &contract.address, &renter, 3, 1000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_required_license_class(&owner, &3);
+    contract.add_verifier_key(&credential.public_key);
+
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &Some(credential));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_credential_with_other_license_class_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let signing_key = SigningKey::generate(&mut OsRng);
+    let credential = sign_credential(&env, &signing_key, &contract.address, &renter, 2, 1000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_required_license_class(&owner, &3);
+    contract.add_verifier_key(&credential.public_key);
+
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &Some(credential));
+}
@@ -0,0 +1,86 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+use crate::{storage::structs::rental_options::RentalOptions, tests::config::{contract::ContractTest, utils::handover_report}};
+
+fn rent(env: &Env, test: &ContractTest) -> (Address, Address, u64) {
+    let owner = Address::generate(env);
+    let renter = Address::generate(env);
+    let (_, token_admin, _) = &test.token;
+    token_admin.mint(&renter, &10_000);
+
+    test.contract.add_car(&owner, &1500, &10);
+    let rental_id = test
+        .contract
+        .rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    test.contract.confirm_pickup(&rental_id, &handover_report(env, 1));
+
+    (owner, renter, rental_id)
+}
+
+#[test]
+pub fn test_add_and_remove_driver() {
+    let test = ContractTest::setup();
+    let env = test.env.clone();
+    env.mock_all_auths();
+
+    let (_, renter, rental_id) = rent(&env, &test);
+    let driver = Address::generate(&env);
+
+    test.contract.add_driver(&rental_id, &driver, &None);
+    assert_eq!(test.contract.get_drivers(&rental_id), vec![&env, renter.clone(), driver.clone()]);
+
+    test.contract.remove_driver(&rental_id, &driver);
+    assert_eq!(test.contract.get_drivers(&rental_id), vec![&env, renter]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #35)")]
+pub fn test_driver_limit() {
+    let test = ContractTest::setup();
+    let env = test.env.clone();
+    env.mock_all_auths();
+
+    let (_, _, rental_id) = rent(&env, &test);
+
+    for _ in 0..4 {
+        test.contract.add_driver(&rental_id, &Address::generate(&env), &None);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_unverified_driver_rejected() {
+    let test = ContractTest::setup();
+    let env = test.env.clone();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let (_, token_admin, _) = &test.token;
+    token_admin.mint(&renter, &10_000);
+
+    test.contract.add_car(&owner, &1500, &10);
+    test.contract.set_requires_verification(&owner, &true);
+    test.contract.add_verifier(&verifier);
+    test.contract.register_attestation(&verifier, &renter, &1000);
+
+    let rental_id = test
+        .contract
+        .rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    test.contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    test.contract.add_driver(&rental_id, &Address::generate(&env), &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #36)")]
+pub fn test_cannot_add_driver_after_return() {
+    let test = ContractTest::setup();
+    let env = test.env.clone();
+    env.mock_all_auths();
+
+    let (owner, _, rental_id) = rent(&env, &test);
+    test.contract.return_car(&owner);
+
+    test.contract.add_driver(&rental_id, &Address::generate(&env), &None);
+}
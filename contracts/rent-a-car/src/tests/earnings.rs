@@ -0,0 +1,53 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+use crate::{
+    storage::structs::rental_options::RentalOptions,
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+pub fn test_owner_cannot_withdraw_before_return() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    contract.payout_owner(&owner, &4500, &None);
+}
+
+#[test]
+pub fn test_earnings_released_pro_rata() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    env.ledger().with_mut(|li| li.timestamp += 2 * 86_400 + 3600);
+    assert_eq!(contract.release_earnings(&rental_id), 3000);
+    assert_eq!(contract.release_earnings(&rental_id), 0);
+
+    contract.payout_owner(&owner, &3000, &None);
+    assert_eq!(token_client.balance(&owner), 3000);
+
+    // La devolución libera el resto
+    contract.return_car(&owner);
+    assert_eq!(contract.get_rental(&rental_id).released, 4500);
+    contract.payout_owner(&owner, &1500, &None);
+    assert_eq!(token_client.balance(&owner), 4500);
+}
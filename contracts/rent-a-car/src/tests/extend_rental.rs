@@ -0,0 +1,181 @@
+use soroban_sdk::{testutils::Address as _, vec, Address};
+use crate::{
+    storage::structs::{
+        organization::SpendingPeriod,
+        pricing_rules::{LongStayDiscount, PricingRules},
+        rental_options::RentalOptions,
+    },
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+#[test]
+pub fn test_extend_rental_charges_extra_days() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    let end_ts = contract.get_rental(&rental_id).end_ts;
+
+    assert_eq!(contract.extend_rental(&rental_id, &2), 3300);
+
+    let rental = contract.get_rental(&rental_id);
+    assert_eq!(rental.end_ts, end_ts + 2 * 86_400);
+    assert_eq!(rental.total_days_to_rent, 5);
+    assert_eq!(rental.amount, 7500);
+    assert_eq!(rental.commission, 750);
+    assert_eq!(token_client.balance(&renter), 10_000 - 4950 - 3300);
+    assert_eq!(contract.get_admin_balance(), 750);
+
+    contract.return_car(&owner);
+    contract.payout_owner(&owner, &7500, &None);
+    assert_eq!(token_client.balance(&owner), 7500);
+}
+
+#[test]
+pub fn test_extension_priced_with_combined_long_stay_discount() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1000, &10);
+    contract.set_pricing_rules(
+        &owner,
+        &PricingRules {
+            weekend_surcharge: 0,
+            seasons: vec![&env],
+            long_stay_discounts: vec![&env, LongStayDiscount { min_days: 7, discount: 10 }],
+        },
+    );
+    let rental_id = contract.rental(&renter, &owner, &5, &5000, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    // 5 + 3 días alcanzan el descuento de 7 días: solo los días extra se cobran con el 10% menos
+    assert_eq!(contract.extend_rental(&rental_id, &3), 2700 + 270);
+
+    let rental = contract.get_rental(&rental_id);
+    assert_eq!(rental.total_days_to_rent, 8);
+    assert_eq!(rental.amount, 5000 + 2700);
+    assert_eq!(token_client.balance(&renter), 10_000 - 5500 - 2970);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #36)")]
+pub fn test_cannot_extend_returned_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
+
+    contract.extend_rental(&rental_id, &2);
+}
+
+#[test]
+pub fn test_extend_org_rental_charges_treasury_and_member_limit() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let org = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let member = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&treasury, &20_000);
+    token_client.approve(&treasury, &contract.address, &20_000, &1000);
+
+    contract.create_organization(&org, &treasury);
+    contract.set_org_member(&org, &member, &10_000, &SpendingPeriod::Monthly);
+    contract.add_car(&owner, &1500, &10);
+
+    let rental_id = contract.rental_on_behalf(&org, &member, &owner, &3, &4500, &RentalOptions::default());
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.extend_rental(&rental_id, &2);
+
+    assert_eq!(token_client.balance(&treasury), 20_000 - 4950 - 3300);
+    assert_eq!(token_client.balance(&member), 0);
+    assert_eq!(contract.get_org_member(&org, &member).spent, 4950 + 3300);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #37)")]
+pub fn test_extend_org_rental_respects_member_limit() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let org = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let member = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&treasury, &20_000);
+    token_client.approve(&treasury, &contract.address, &20_000, &1000);
+
+    contract.create_organization(&org, &treasury);
+    contract.set_org_member(&org, &member, &6000, &SpendingPeriod::Monthly);
+    contract.add_car(&owner, &1500, &10);
+
+    let rental_id = contract.rental_on_behalf(&org, &member, &owner, &3, &4500, &RentalOptions::default());
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.extend_rental(&rental_id, &2);
+}
+
+#[test]
+pub fn test_extend_sponsored_rental_uses_allowance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+    token_client.approve(&renter, &relayer, &9000, &1000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.sponsored_rental(&relayer, &renter, &owner, &3, &4500, &RentalOptions::default());
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.extend_rental(&rental_id, &2);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - 4950 - 3300);
+    assert_eq!(token_client.allowance(&renter, &relayer), 9000 - 4950 - 3300);
+}
+
+#[test]
+pub fn test_extend_insured_rental_extends_premium() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.set_insurance_config(&5, &800);
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions { insured: true, ..Default::default() }, &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    // 3000 de alquiler + 300 de comisión + 150 de prima
+    assert_eq!(contract.extend_rental(&rental_id, &2), 3450);
+    assert_eq!(contract.get_rental(&rental_id).insurance_premium, 375);
+    assert_eq!(contract.get_insurance_pool_balance(), 375);
+    assert_eq!(token_client.balance(&renter), 10_000 - 5175 - 3450);
+}
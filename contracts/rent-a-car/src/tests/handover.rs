@@ -0,0 +1,184 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+use crate::{
+    storage::{
+        structs::rental_options::RentalOptions,
+        types::{car_status::CarStatus, rental_status::RentalStatus},
+    },
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+#[test]
+pub fn test_pickup_and_return_are_recorded() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    env.ledger().set_timestamp(100);
+    let mut pickup = handover_report(&env, 1);
+    pickup.odometer = Some(12_000);
+    pickup.fuel_level = Some(100);
+    contract.confirm_pickup(&rental_id, &pickup);
+
+    env.ledger().set_timestamp(200_000);
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    assert_eq!(contract.get_pickup(&rental_id).report, pickup);
+    assert_eq!(contract.get_pickup(&rental_id).timestamp, 100);
+    assert_eq!(contract.get_return(&rental_id).timestamp, 200_000);
+    assert_eq!(contract.get_rental(&rental_id).status, RentalStatus::Returned);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+}
+
+#[test]
+pub fn test_reserved_rental_can_be_cancelled_at_booking() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.cancel_rental(&rental_id);
+
+    assert_eq!(token_client.balance(&renter), 10_000);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+}
+
+#[test]
+pub fn test_late_cancellation_pays_days_held() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    // Una hora de reserva ya cuenta como un día
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    contract.cancel_rental(&rental_id);
+
+    assert_eq!(token_client.balance(&renter), 10_000 - 1500);
+    contract.payout_owner(&owner, &1500, &None);
+    assert_eq!(token_client.balance(&owner), 1500);
+}
+
+#[test]
+pub fn test_cancellation_after_end_forfeits_rent() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    env.ledger().with_mut(|li| li.timestamp += 10 * 86_400);
+    contract.cancel_rental(&rental_id);
+
+    // Solo se devuelve la comisión
+    assert_eq!(token_client.balance(&renter), 10_000 - 4500);
+}
+
+#[test]
+pub fn test_anyone_can_expire_reservation_after_pickup_window() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_car_deposit(&owner, &1000);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_400 + 1);
+    contract.expire_reservation(&rental_id);
+
+    // Sin entrega confirmada, el renter recupera el pago completo
+    assert_eq!(contract.get_rental(&rental_id).status, RentalStatus::Cancelled);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+    assert_eq!(token_client.balance(&renter), 10_000);
+    assert_eq!(contract.get_admin_balance(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+pub fn test_reservation_cannot_expire_within_pickup_window() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    contract.expire_reservation(&rental_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #38)")]
+pub fn test_claim_requires_condition_change() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &0);
+    contract.set_car_deposit(&owner, &2000);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    contract.set_arbiter(&Address::generate(&env));
+    contract.file_claim(&rental_id, &1500, &handover_report(&env, 9).condition_hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+pub fn test_claim_requires_signed_return_report() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &0);
+    contract.set_car_deposit(&owner, &2000);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    // Devolver solo con la firma del owner no habilita a reclamar daños sin comparar informes
+    contract.return_car(&owner);
+    contract.file_claim(&rental_id, &1500, &handover_report(&env, 9).condition_hash);
+}
@@ -0,0 +1,71 @@
+use soroban_sdk::{testutils::Address as _, Address, BytesN};
+use crate::{
+    storage::{car::read_car, structs::rental_options::RentalOptions},
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+#[test]
+pub fn test_insured_rental_pays_premium_into_pool() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.set_insurance_config(&5, &800);
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions { insured: true, ..Default::default() }, &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    assert_eq!(contract.get_rental(&rental_id).insurance_premium, 225);
+    assert_eq!(contract.get_insurance_pool_balance(), 225);
+    assert_eq!(token_client.balance(&renter), 10_000 - 4500 - 450 - 225);
+}
+
+#[test]
+pub fn test_claim_is_paid_from_pool_before_deposit() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+    token_admin.mint(&admin, &1000);
+
+    contract.set_insurance_config(&5, &800);
+    contract.top_up_insurance_pool(&1000);
+    contract.set_arbiter(&Address::generate(&env));
+
+    contract.add_car(&owner, &1500, &0);
+    contract.set_car_deposit(&owner, &2000);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions { insured: true, ..Default::default() }, &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 2));
+
+    contract.file_claim(&rental_id, &1500, &BytesN::from_array(&env, &[1; 32]));
+    contract.resolve_claim(&rental_id, &1200);
+
+    // El fondo cubre 800 y los 400 restantes salen del depósito
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    assert_eq!(car.available_to_withdraw, 4500 + 1200);
+    assert_eq!(contract.get_insurance_pool_balance(), 1000 + 225 - 800);
+    assert_eq!(token_client.balance(&renter), 10_000 - 4500 - 225 - 400);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+pub fn test_insured_rental_without_config_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions { insured: true, ..Default::default() }, &None);
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+use soroban_sdk::{testutils::Address as _, Address, String};
+use crate::{
+    storage::{car::{has_car, read_car}, structs::rental_options::RentalOptions, types::car_status::CarStatus},
+    tests::config::contract::ContractTest,
+};
+
+#[test]
+pub fn test_request_listing_creates_pending_car() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let metadata = String::from_str(&env, "Toyota Corolla 2022");
+    let price_per_day = 1500_i128;
+
+    contract.request_listing(&owner, &metadata, &price_per_day);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    assert_eq!(car.car_status, CarStatus::PendingApproval);
+    assert_eq!(car.price_per_day, price_per_day);
+    assert_eq!(car.metadata, metadata);
+}
+
+#[test]
+pub fn test_fleet_manager_approves_listing() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let comission = 10_i128;
+
+    contract.add_fleet_manager(&manager);
+    contract.request_listing(&owner, &String::from_str(&env, "Mazda 3"), &1500);
+    contract.approve_listing(&manager, &owner, &comission);
+
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    assert_eq!(car.car_status, CarStatus::Available);
+    assert_eq!(car.comission_to_admin, comission);
+}
+
+#[test]
+pub fn test_admin_rejects_listing() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.request_listing(&owner, &String::from_str(&env, "Mazda 3"), &1500);
+    contract.reject_listing(&admin, &owner, &String::from_str(&env, "Documentos incompletos"));
+
+    assert!(!env.as_contract(&contract.address, || has_car(&env, &owner)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_unknown_address_cannot_approve_listing() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    contract.request_listing(&owner, &String::from_str(&env, "Mazda 3"), &1500);
+    contract.approve_listing(&stranger, &owner, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+pub fn test_pending_car_cannot_be_rented() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.request_listing(&owner, &String::from_str(&env, "Mazda 3"), &1500);
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+}
\ No newline at end of file
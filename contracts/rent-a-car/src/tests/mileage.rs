@@ -0,0 +1,119 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{
+    storage::structs::{odometer::OdometerReadings, rental_options::RentalOptions},
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+#[test]
+pub fn test_overage_charged_from_deposit_and_renter() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &0);
+    contract.set_car_deposit(&owner, &1000);
+    contract.set_mileage_policy(&owner, &100, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    let mut pickup = handover_report(&env, 1);
+    pickup.odometer = Some(10_000);
+    contract.confirm_pickup(&rental_id, &pickup);
+
+    let mut ret = handover_report(&env, 1);
+    ret.odometer = Some(10_450);
+    contract.confirm_return(&rental_id, &ret);
+
+    // 450 km recorridos, 300 incluidos: 150 km × 10 = 1500, de los cuales 1000 salen del depósito
+    assert_eq!(contract.get_rental(&rental_id).deposit, 0);
+    assert_eq!(token_client.balance(&renter), 10_000 - 4500 - 1000 - 500);
+
+    contract.payout_owner(&owner, &6000, &None);
+    assert_eq!(token_client.balance(&owner), 6000);
+}
+
+#[test]
+pub fn test_reporter_readings_take_precedence() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let reporter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &0);
+    contract.set_mileage_policy(&owner, &100, &10);
+    contract.set_telematics_reporter(&owner, &reporter);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    contract.report_odometer(&rental_id, &1000);
+
+    let mut pickup = handover_report(&env, 1);
+    pickup.odometer = Some(5000);
+    contract.confirm_pickup(&rental_id, &pickup);
+
+    contract.report_odometer(&rental_id, &1200);
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    assert_eq!(
+        contract.get_odometer_readings(&rental_id),
+        OdometerReadings { start: Some(1000), end: Some(1200) }
+    );
+    assert_eq!(token_client.balance(&renter), 10_000 - 4500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+pub fn test_report_odometer_requires_registered_reporter() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &0);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    contract.report_odometer(&rental_id, &1000);
+}
+
+#[test]
+pub fn test_return_report_overrides_earlier_reporter_reading() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let reporter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &0);
+    contract.set_mileage_policy(&owner, &100, &10);
+    contract.set_telematics_reporter(&owner, &reporter);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    let mut pickup = handover_report(&env, 1);
+    pickup.odometer = Some(10_000);
+    contract.confirm_pickup(&rental_id, &pickup);
+
+    // Lectura intermedia del reporter; el auto sigue rodando hasta la devolución
+    contract.report_odometer(&rental_id, &10_200);
+
+    let mut ret = handover_report(&env, 1);
+    ret.odometer = Some(10_450);
+    contract.confirm_return(&rental_id, &ret);
+
+    assert_eq!(
+        contract.get_odometer_readings(&rental_id),
+        OdometerReadings { start: Some(10_000), end: Some(10_450) }
+    );
+    assert_eq!(token_client.balance(&renter), 10_000 - 4500 - 1500);
+}
@@ -6,4 +6,29 @@ pub mod add_car;
 pub mod get_car_status;
 pub mod rental;
 pub mod remove_car;
-pub mod payout_owner;
\ No newline at end of file
+pub mod payout_owner;
+pub mod listing;
+pub mod pricing_rules;
+pub mod promo_code;
+pub mod rental_history;
+pub mod rental_status;
+pub mod claim;
+pub mod insurance;
+pub mod reputation;
+pub mod verification;
+pub mod credential;
+pub mod blocklist;
+pub mod driver;
+pub mod organization;
+pub mod sponsored_rental;
+pub mod extend_rental;
+pub mod overdue;
+pub mod handover;
+pub mod mileage;
+pub mod telematics;
+pub mod earnings;
+pub mod auto_payout;
+pub mod payout_destination;
+pub mod beneficiary;
+pub mod car_token;
+pub mod shares;
@@ -0,0 +1,132 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+use crate::{
+    storage::structs::{organization::SpendingPeriod, rental_options::RentalOptions},
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+#[test]
+pub fn test_rental_on_behalf_charges_treasury() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let org = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let member = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&treasury, &10_000);
+    token_client.approve(&treasury, &contract.address, &10_000, &1000);
+
+    contract.create_organization(&org, &treasury);
+    contract.set_org_member(&org, &member, &5000, &SpendingPeriod::Monthly);
+    contract.add_car(&owner, &1500, &10);
+
+    let rental_id = contract.rental_on_behalf(&org, &member, &owner, &3, &4500, &RentalOptions::default());
+
+    let rental = contract.get_rental(&rental_id);
+    assert_eq!(rental.renter, member);
+    assert_eq!(rental.payer, treasury);
+    assert_eq!(token_client.balance(&treasury), 10_000 - 4950);
+    assert_eq!(token_client.balance(&member), 0);
+    assert_eq!(contract.get_org_member(&org, &member).spent, 4950);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #37)")]
+pub fn test_rental_on_behalf_over_limit() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let org = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let member = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&treasury, &10_000);
+    token_client.approve(&treasury, &contract.address, &10_000, &1000);
+
+    contract.create_organization(&org, &treasury);
+    contract.set_org_member(&org, &member, &4000, &SpendingPeriod::Daily);
+    contract.add_car(&owner, &1500, &10);
+
+    contract.rental_on_behalf(&org, &member, &owner, &3, &4500, &RentalOptions::default());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_rental_on_behalf_requires_membership() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let org = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let member = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&treasury, &10_000);
+    token_client.approve(&treasury, &contract.address, &10_000, &1000);
+
+    contract.create_organization(&org, &treasury);
+    contract.add_car(&owner, &1500, &10);
+
+    contract.rental_on_behalf(&org, &member, &owner, &3, &4500, &RentalOptions::default());
+}
+
+#[test]
+pub fn test_daily_limit_resets() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let org = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let member = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&treasury, &20_000);
+    token_client.approve(&treasury, &contract.address, &20_000, &1000);
+
+    contract.create_organization(&org, &treasury);
+    contract.set_org_member(&org, &member, &2000, &SpendingPeriod::Daily);
+    contract.add_car(&owner, &1500, &10);
+
+    let rental_id = contract.rental_on_behalf(&org, &member, &owner, &1, &1500, &RentalOptions::default());
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+
+    contract.rental_on_behalf(&org, &member, &owner, &1, &1500, &RentalOptions::default());
+    assert_eq!(contract.get_org_member(&org, &member).spent, 1650);
+}
+
+#[test]
+pub fn test_create_organization_requires_treasury_auth() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let org = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    contract.create_organization(&org, &treasury);
+
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(address, _)| address).collect();
+    assert!(signers.contains(&org));
+    assert!(signers.contains(&treasury));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+pub fn test_treasury_cannot_back_two_organizations() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let treasury = Address::generate(&env);
+
+    contract.create_organization(&Address::generate(&env), &treasury);
+    contract.create_organization(&Address::generate(&env), &treasury);
+}
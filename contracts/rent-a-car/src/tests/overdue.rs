@@ -0,0 +1,217 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN,
+};
+use crate::{
+    storage::{
+        structs::rental_options::RentalOptions,
+        types::{car_status::CarStatus, rental_status::RentalStatus},
+    },
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+#[test]
+pub fn test_settle_overdue_charges_penalty_and_rewards_keeper() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_car_deposit(&owner, &4000);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    // Dos días de atraso, superada la tolerancia de un día
+    env.ledger().with_mut(|li| li.timestamp += 5 * 86_400);
+    contract.settle_overdue(&keeper, &rental_id);
+
+    // Penalidad: 2 días × 1500 = 3000, el keeper cobra el 10% y el owner el resto
+    let rental = contract.get_rental(&rental_id);
+    assert_eq!(token_client.balance(&keeper), 300);
+    assert_eq!(token_client.balance(&renter), 10_000 - 4950 - 4000);
+    assert_eq!(rental.status, RentalStatus::Overdue);
+    assert_eq!(rental.deposit, 1000);
+    assert_eq!(rental.overdue_ts, Some(env.ledger().timestamp()));
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Rented);
+
+    contract.payout_owner(&owner, &(4500 + 2700), &None);
+    assert_eq!(token_client.balance(&owner), 7200);
+}
+
+#[test]
+pub fn test_penalty_stops_at_overdue_settlement() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &20_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_car_deposit(&owner, &6000);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    env.ledger().with_mut(|li| li.timestamp += 5 * 86_400);
+    contract.settle_overdue(&keeper, &rental_id);
+
+    // Demorar la devolución no suma penalidad
+    env.ledger().with_mut(|li| li.timestamp += 3 * 86_400);
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    let rental = contract.get_rental(&rental_id);
+    assert_eq!(rental.status, RentalStatus::Returned);
+    assert_eq!(rental.deposit, 6000 - 3000);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+
+    env.ledger().with_mut(|li| li.timestamp += 4 * 86_400);
+    contract.release_deposit(&rental_id);
+    assert_eq!(token_client.balance(&renter), 20_000 - 4950 - 3000);
+}
+
+#[test]
+pub fn test_deposit_released_when_owner_never_returns_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &20_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_car_deposit(&owner, &6000);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    env.ledger().with_mut(|li| li.timestamp += 5 * 86_400);
+    contract.settle_overdue(&keeper, &rental_id);
+
+    // El owner nunca devuelve el auto ni reclama: vencido el plazo, el resto del depósito vuelve al renter
+    env.ledger().with_mut(|li| li.timestamp += 10 * 86_400);
+    contract.release_deposit(&rental_id);
+
+    assert_eq!(contract.get_rental(&rental_id).status, RentalStatus::Settled);
+    assert_eq!(token_client.balance(&renter), 20_000 - 4950 - 3000);
+
+    // La devolución posterior solo libera el auto
+    contract.return_car(&owner);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+    assert_eq!(token_client.balance(&renter), 20_000 - 4950 - 3000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+pub fn test_overdue_deposit_held_during_claim_window() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &20_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_car_deposit(&owner, &6000);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    env.ledger().with_mut(|li| li.timestamp += 5 * 86_400);
+    contract.settle_overdue(&keeper, &rental_id);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    contract.release_deposit(&rental_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_renter_cannot_settle_own_overdue_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_car_deposit(&owner, &3000);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    env.ledger().with_mut(|li| li.timestamp += 5 * 86_400);
+    contract.settle_overdue(&renter, &rental_id);
+}
+
+#[test]
+pub fn test_owner_can_claim_on_unreturned_overdue_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_car_deposit(&owner, &3000);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    env.ledger().with_mut(|li| li.timestamp += 10 * 86_400);
+    contract.settle_overdue(&keeper, &rental_id);
+    contract.file_claim(&rental_id, &2000, &BytesN::from_array(&env, &[7; 32]));
+
+    assert_eq!(contract.get_rental(&rental_id).status, RentalStatus::Disputed);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+pub fn test_settle_overdue_before_grace_period() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    env.ledger().with_mut(|li| li.timestamp += 3 * 86_400 + 3600);
+    contract.settle_overdue(&keeper, &rental_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #36)")]
+pub fn test_settle_overdue_rejects_returned_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
+
+    env.ledger().with_mut(|li| li.timestamp += 10 * 86_400);
+    contract.settle_overdue(&keeper, &rental_id);
+}
@@ -0,0 +1,108 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+use crate::{
+    storage::structs::rental_options::RentalOptions,
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+#[test]
+pub fn test_payout_goes_to_registered_destination() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let cold_wallet = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_payout_destination(&owner, &cold_wallet);
+
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
+
+    contract.payout_owner(&owner, &4500, &None);
+    assert_eq!(token_client.balance(&cold_wallet), 4500);
+}
+
+#[test]
+pub fn test_destination_change_waits_for_timelock() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let cold_wallet = Address::generate(&env);
+    let new_wallet = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_payout_destination(&owner, &cold_wallet);
+    contract.set_payout_destination(&owner, &new_wallet);
+    assert_eq!(contract.get_payout_destination(&owner), cold_wallet);
+
+    env.ledger().with_mut(|li| li.timestamp += 2 * 86_400);
+    assert_eq!(contract.get_payout_destination(&owner), new_wallet);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_explicit_to_must_match_registered_destination() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_payout_destination(&owner, &Address::generate(&env));
+
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
+
+    contract.payout_owner(&owner, &4500, &Some(Address::generate(&env)));
+}
+
+#[test]
+pub fn test_payout_admin_to_explicit_address() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    contract.payout_admin(&450, &Some(treasury.clone()));
+    assert_eq!(token_client.balance(&treasury), 450);
+    assert_eq!(contract.get_admin_balance(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+pub fn test_payout_admin_limited_to_commission() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_car_deposit(&owner, &2000);
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    // El contrato retiene 6950, pero solo 450 son comisión
+    contract.payout_admin(&300, &None);
+    contract.payout_admin(&300, &None);
+}
@@ -1,12 +1,13 @@
 use soroban_sdk::{testutils::Address as _, Address};
 use crate::{
-    storage::{car::read_car, contract_balance::read_contract_balance},
-    tests::config::contract::ContractTest,
+    storage::{car::read_car, contract_balance::read_contract_balance, structs::rental_options::RentalOptions},
+    tests::config::{contract::ContractTest, utils::handover_report},
 };
 
 #[test]
 pub fn test_payout_owner_successfully() {
     let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -20,16 +21,19 @@ pub fn test_payout_owner_successfully() {
     token_admin.mint(&renter, &amount_mint);
 
     contract.add_car(&owner, &price_per_day, &comission);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let rental_id = contract.rental(&renter, &owner, &total_days, &amount, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
 
+    let commission = amount * comission / 100;
     let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
-    assert_eq!(contract_balance, amount);
+    assert_eq!(contract_balance, amount + commission);
 
-    contract.payout_owner(&owner, &amount);
+    contract.payout_owner(&owner, &amount, &None);
 
     let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
     assert_eq!(car.available_to_withdraw, 0);
 
     let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
-    assert_eq!(contract_balance, 0);
+    assert_eq!(contract_balance, commission);
 }
\ No newline at end of file
@@ -0,0 +1,150 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address};
+use crate::{
+    constants::DAY_IN_SECONDS,
+    storage::structs::{
+        pricing_rules::{LongStayDiscount, PricingRules, SeasonalRate},
+        rental_options::RentalOptions,
+    },
+    tests::config::contract::ContractTest,
+};
+
+const THURSDAY: u64 = 0;
+const FRIDAY: u64 = DAY_IN_SECONDS;
+const MONDAY: u64 = 4 * DAY_IN_SECONDS;
+
+#[test]
+pub fn test_quote_applies_weekend_surcharge() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000, &10);
+    contract.set_pricing_rules(
+        &owner,
+        &PricingRules {
+            weekend_surcharge: 20,
+            seasons: vec![&env],
+            long_stay_discounts: vec![&env],
+        },
+    );
+
+    // Viernes, sábado y domingo
+    env.ledger().set_timestamp(FRIDAY);
+    assert_eq!(contract.get_quote(&owner, &3), 1000 + 1200 + 1200);
+}
+
+#[test]
+pub fn test_quote_applies_seasonal_multiplier() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000, &10);
+    contract.set_pricing_rules(
+        &owner,
+        &PricingRules {
+            weekend_surcharge: 0,
+            seasons: vec![
+                &env,
+                SeasonalRate {
+                    start_ts: THURSDAY,
+                    end_ts: THURSDAY + DAY_IN_SECONDS,
+                    multiplier: 150,
+                },
+            ],
+            long_stay_discounts: vec![&env],
+        },
+    );
+
+    env.ledger().set_timestamp(THURSDAY);
+    assert_eq!(contract.get_quote(&owner, &2), 1500 + 1000);
+}
+
+#[test]
+pub fn test_quote_applies_best_long_stay_discount() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000, &10);
+    contract.set_pricing_rules(
+        &owner,
+        &PricingRules {
+            weekend_surcharge: 0,
+            seasons: vec![&env],
+            long_stay_discounts: vec![
+                &env,
+                LongStayDiscount { min_days: 7, discount: 10 },
+                LongStayDiscount { min_days: 30, discount: 25 },
+            ],
+        },
+    );
+
+    env.ledger().set_timestamp(MONDAY);
+    assert_eq!(contract.get_quote(&owner, &6), 6000);
+    assert_eq!(contract.get_quote(&owner, &7), 6300);
+    assert_eq!(contract.get_quote(&owner, &30), 22_500);
+}
+
+#[test]
+pub fn test_rental_charges_quoted_price() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1000, &10);
+    contract.set_pricing_rules(
+        &owner,
+        &PricingRules {
+            weekend_surcharge: 20,
+            seasons: vec![&env],
+            long_stay_discounts: vec![&env],
+        },
+    );
+
+    env.ledger().set_timestamp(FRIDAY);
+    let rental_id = contract.rental(&renter, &owner, &3, &5000, &RentalOptions::default(), &None);
+
+    assert_eq!(contract.get_rental(&rental_id).amount, 3400);
+    assert_eq!(token_client.balance(&renter), 10_000 - 3400 - 340);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+pub fn test_rental_below_quote_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1000, &10);
+    contract.rental(&renter, &owner, &3, &2999, &RentalOptions::default(), &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+pub fn test_invalid_season_is_rejected() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000, &10);
+    contract.set_pricing_rules(
+        &owner,
+        &PricingRules {
+            weekend_surcharge: 0,
+            seasons: vec![
+                &env,
+                SeasonalRate { start_ts: FRIDAY, end_ts: THURSDAY, multiplier: 150 },
+            ],
+            long_stay_discounts: vec![&env],
+        },
+    );
+}
\ No newline at end of file
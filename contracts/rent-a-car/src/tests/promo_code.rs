@@ -0,0 +1,111 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, BytesN, Env};
+use crate::{
+    storage::structs::{promo_code::Discount, rental_options::RentalOptions},
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+fn hash_code(env: &Env, code: &Bytes) -> BytesN<32> {
+    env.crypto().sha256(code).into()
+}
+
+#[test]
+pub fn test_rental_with_percentage_promo_code() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let code = Bytes::from_slice(&env, b"VERANO10");
+    contract.add_car(&owner, &1500, &10);
+    contract.create_promo_code(&hash_code(&env, &code), &Discount::Percentage(10), &1000, &5, &None);
+
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions { promo_code: Some(code.clone()), ..Default::default() }, &None);
+
+    // El descuento se aplica antes de la comisión: 4500 - 10% = 4050, comisión 405
+    assert_eq!(contract.get_rental(&rental_id).amount, 4050);
+    assert_eq!(token_client.balance(&renter), 10_000 - 4050 - 405);
+    assert_eq!(contract.get_promo_code(&hash_code(&env, &code)).uses, 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")]
+pub fn test_promo_code_restricted_to_other_car_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let code = Bytes::from_slice(&env, b"MAZDA500");
+    contract.add_car(&owner, &1500, &10);
+    contract.create_promo_code(
+        &hash_code(&env, &code),
+        &Discount::Fixed(500),
+        &1000,
+        &5,
+        &Some(other_owner),
+    );
+
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions { promo_code: Some(code), ..Default::default() }, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+pub fn test_expired_promo_code_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    let code = Bytes::from_slice(&env, b"VERANO10");
+    contract.add_car(&owner, &1500, &10);
+    contract.create_promo_code(&hash_code(&env, &code), &Discount::Percentage(10), &1000, &5, &None);
+
+    env.ledger().set_timestamp(1000);
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions { promo_code: Some(code), ..Default::default() }, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+pub fn test_exhausted_promo_code_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &20_000);
+
+    let code = Bytes::from_slice(&env, b"UNICO");
+    contract.add_car(&owner, &1500, &10);
+    contract.create_promo_code(&hash_code(&env, &code), &Discount::Fixed(500), &1000, &1, &None);
+
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions { promo_code: Some(code.clone()), ..Default::default() }, &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions { promo_code: Some(code), ..Default::default() }, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+pub fn test_unknown_promo_code_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions { promo_code: Some(Bytes::from_slice(&env, b"NO_EXISTE")), ..Default::default() }, &None);
+}
\ No newline at end of file
@@ -1,10 +1,14 @@
-use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
-use crate::{storage::{car::has_car}, tests::config::contract::ContractTest};
+use soroban_sdk::{testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::{
+    constants::CLAIM_WINDOW,
+    storage::{car::has_car, structs::rental_options::RentalOptions},
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
 
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_remove_car() {
-    let ContractTest { env, contract, admin,.. } = ContractTest::setup();
+    let ContractTest { env, contract, .. } = ContractTest::setup();
 
 	let fake_admin = Address::generate(&env);
     let owner = Address::generate(&env);
@@ -23,7 +27,7 @@ pub fn test_unauthorized_user_cannot_remove_car() {
 
 #[test]
 pub fn test_remove_car_deletes_from_storage() {
-    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    let ContractTest { env, contract, .. } = ContractTest::setup();
     env.mock_all_auths();
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
@@ -37,4 +41,64 @@ pub fn test_remove_car_deletes_from_storage() {
     assert!(!env.as_contract(&contract.address, || {
         has_car(&env, &owner)
     }));
-}
\ No newline at end of file
+}
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+pub fn test_rented_car_cannot_be_removed() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    token.1.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    contract.remove_car(&owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+pub fn test_car_with_unpaid_earnings_cannot_be_removed() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    token.1.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    contract.remove_car(&owner);
+}
+
+#[test]
+pub fn test_settled_car_can_be_removed() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    token.1.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_car_deposit(&owner, &1000);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+    contract.payout_owner(&owner, &4500, &None);
+
+    // El depósito sin liberar todavía impide la baja
+    assert!(contract.try_remove_car(&owner).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp += CLAIM_WINDOW + 1);
+    contract.release_deposit(&rental_id);
+    contract.remove_car(&owner);
+
+    assert!(!env.as_contract(&contract.address, || has_car(&env, &owner)));
+}
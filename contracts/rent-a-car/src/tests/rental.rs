@@ -1,5 +1,5 @@
 use soroban_sdk::{testutils::Address as _, Address, vec, IntoVal, Symbol};
-use crate::{storage::{car::read_car, contract_balance::read_contract_balance, rental::read_rental, types::car_status::CarStatus}, tests::config::{contract::ContractTest, utils::get_contract_events}};
+use crate::{storage::{car::read_car, contract_balance::read_contract_balance, rental::read_rental, structs::rental_options::RentalOptions, types::car_status::CarStatus}, tests::config::{contract::ContractTest, utils::get_contract_events}};
 
 #[test]
 pub fn test_rental_car_successfully() {
@@ -24,17 +24,21 @@ pub fn test_rental_car_successfully() {
     let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
     assert_eq!(initial_contract_balance, 0);
 
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let rental_id = contract.rental(&renter, &owner, &total_days, &amount, &RentalOptions::default(), &None);
     let contract_events = get_contract_events(&env, &contract.address);
 
+    let commission = amount * comission / 100;
     let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
-    assert_eq!(updated_contract_balance, amount);
+    assert_eq!(updated_contract_balance, amount + commission);
 
     let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
     assert_eq!(car.car_status, CarStatus::Rented);
-    assert_eq!(car.available_to_withdraw, amount);
+    // El monto del owner queda retenido hasta la devolución
+    assert_eq!(car.available_to_withdraw, 0);
 
-    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner)).unwrap();
+    let rental = env.as_contract(&contract.address, || read_rental(&env, rental_id)).unwrap();
+    assert_eq!(rental.renter, renter);
+    assert_eq!(rental.owner, owner);
     assert_eq!(rental.total_days_to_rent, total_days);
     assert_eq!(rental.amount, amount);
         assert_eq!(
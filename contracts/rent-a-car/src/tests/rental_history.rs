@@ -0,0 +1,65 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{storage::structs::rental_options::RentalOptions, tests::config::{contract::ContractTest, utils::handover_report}};
+
+#[test]
+pub fn test_repeated_rentals_keep_history() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &20_000);
+
+    contract.add_car(&owner, &1500, &10);
+
+    let first_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&first_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
+    let second_id = contract.rental(&renter, &owner, &2, &3000, &RentalOptions::default(), &None);
+
+    assert_ne!(first_id, second_id);
+    assert_eq!(contract.get_rental(&first_id).total_days_to_rent, 3);
+    assert_eq!(contract.get_rental(&second_id).total_days_to_rent, 2);
+
+    let rentals = contract.get_rentals_by_renter(&renter, &0, &10);
+    assert_eq!(rentals.len(), 2);
+    assert_eq!(rentals.get(0).unwrap().id, first_id);
+    assert_eq!(rentals.get(1).unwrap().id, second_id);
+}
+
+#[test]
+pub fn test_get_rentals_by_car_is_paginated() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    contract.add_car(&owner, &1000, &0);
+
+    for _ in 0..3 {
+        let renter = Address::generate(&env);
+        token_admin.mint(&renter, &1000);
+        let rental_id = contract.rental(&renter, &owner, &1, &1000, &RentalOptions::default(), &None);
+        contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+        contract.return_car(&owner);
+    }
+
+    let first_page = contract.get_rentals_by_car(&owner, &0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get(0).unwrap().id, 1);
+    assert_eq!(first_page.get(1).unwrap().id, 2);
+
+    let second_page = contract.get_rentals_by_car(&owner, &2, &2);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get(0).unwrap().id, 3);
+
+    assert_eq!(contract.get_rentals_by_car(&owner, &5, &2).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+pub fn test_get_unknown_rental_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+    contract.get_rental(&1);
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{
+    methods::rental::status::can_transition,
+    storage::{structs::rental_options::RentalOptions, types::rental_status::RentalStatus},
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+#[test]
+pub fn test_rental_lifecycle_updates_status() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    assert_eq!(contract.get_rental(&rental_id).status, RentalStatus::Reserved);
+
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    assert_eq!(contract.get_rental(&rental_id).status, RentalStatus::Active);
+
+    contract.return_car(&owner);
+    assert_eq!(contract.get_rental(&rental_id).status, RentalStatus::Returned);
+}
+
+#[test]
+pub fn test_allowed_transitions() {
+    use RentalStatus::*;
+
+    assert!(can_transition(Reserved, Active));
+    assert!(can_transition(Reserved, Cancelled));
+    assert!(can_transition(Active, Returned));
+    assert!(can_transition(Returned, Settled));
+    assert!(can_transition(Disputed, Settled));
+
+    assert!(!can_transition(Active, Cancelled));
+    assert!(!can_transition(Returned, Active));
+    assert!(!can_transition(Settled, Disputed));
+    assert!(!can_transition(Cancelled, Active));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #28)")]
+pub fn test_active_rental_cannot_be_cancelled() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.cancel_rental(&rental_id);
+}
\ No newline at end of file
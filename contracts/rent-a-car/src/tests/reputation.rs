@@ -0,0 +1,89 @@
+use soroban_sdk::{testutils::Address as _, Address, BytesN};
+use crate::{
+    storage::structs::{rental_options::RentalOptions, reputation::Reputation},
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+#[test]
+pub fn test_both_parties_rate_after_return() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+    let review = BytesN::from_array(&env, &[7; 32]);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
+
+    contract.rate_rental(&rental_id, &renter, &5, &review);
+    contract.rate_rental(&rental_id, &owner, &4, &review);
+
+    assert_eq!(contract.get_reputation(&owner), Reputation { count: 1, sum: 5 });
+    assert_eq!(contract.get_reputation(&renter), Reputation { count: 1, sum: 4 });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+pub fn test_party_cannot_rate_twice() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+    let review = BytesN::from_array(&env, &[7; 32]);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&owner);
+
+    contract.rate_rental(&rental_id, &renter, &5, &review);
+    contract.rate_rental(&rental_id, &renter, &1, &review);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+pub fn test_cannot_rate_before_return() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.rate_rental(&rental_id, &renter, &5, &BytesN::from_array(&env, &[7; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_min_renter_rating_is_enforced() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&other_owner, &1000, &0);
+    let rental_id = contract.rental(&renter, &other_owner, &1, &1000, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.return_car(&other_owner);
+    contract.rate_rental(&rental_id, &other_owner, &2, &BytesN::from_array(&env, &[7; 32]));
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_min_renter_rating(&owner, &4);
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+}
\ No newline at end of file
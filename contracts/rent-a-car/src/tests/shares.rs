@@ -0,0 +1,280 @@
+use soroban_sdk::{testutils::Address as _, token, vec, Address};
+use crate::{
+    storage::{
+        car::read_car,
+        structs::{beneficiary::Beneficiary, rental_options::RentalOptions},
+    },
+    tests::config::{
+        contract::ContractTest,
+        utils::{create_share_token, handover_report},
+    },
+};
+
+#[test]
+pub fn test_revenue_distributed_pro_rata_to_shareholders() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    let shares = create_share_token(&env, &contract, &owner);
+    contract.issue_shares(&owner, &1_000);
+    shares.transfer(&owner, &investor, &400);
+
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    assert_eq!(contract.claimable_revenue(&owner, &owner), 2700);
+    assert_eq!(contract.claimable_revenue(&owner, &investor), 1800);
+
+    // El ingreso repartido no pasa por el saldo retirable del auto
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    assert_eq!(car.available_to_withdraw, 0);
+
+    assert_eq!(contract.claim_revenue(&owner, &investor), 1800);
+    assert_eq!(token_client.balance(&investor), 1800);
+    assert_eq!(contract.claimable_revenue(&owner, &investor), 0);
+    assert_eq!(contract.claim_revenue(&owner, &investor), 0);
+}
+
+#[test]
+pub fn test_transfer_settles_revenue_before_moving_shares() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &20_000);
+
+    contract.add_car(&owner, &1000, &0);
+    let shares = create_share_token(&env, &contract, &owner);
+    contract.issue_shares(&owner, &100);
+
+    let rental_id = contract.rental(&renter, &owner, &1, &1000, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    // El inversor compra la mitad después del primer alquiler: solo cobra del segundo
+    shares.transfer(&owner, &investor, &50);
+
+    let rental_id = contract.rental(&renter, &owner, &1, &1000, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    assert_eq!(contract.claimable_revenue(&owner, &owner), 1500);
+    assert_eq!(contract.claimable_revenue(&owner, &investor), 500);
+    assert_eq!(shares.balance(&owner), 50);
+    assert_eq!(shares.balance(&investor), 50);
+}
+
+#[test]
+pub fn test_rounding_dust_carries_to_next_distribution() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &20_000);
+
+    contract.add_car(&owner, &1000, &0);
+    let shares = create_share_token(&env, &contract, &owner);
+    contract.issue_shares(&owner, &3);
+    shares.transfer(&owner, &a, &1);
+    shares.transfer(&owner, &b, &1);
+
+    for _ in 0..2 {
+        let rental_id = contract.rental(&renter, &owner, &1, &1000, &RentalOptions::default(), &None);
+        contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+        contract.confirm_return(&rental_id, &handover_report(&env, 1));
+    }
+
+    let total = contract.claimable_revenue(&owner, &owner)
+        + contract.claimable_revenue(&owner, &a)
+        + contract.claimable_revenue(&owner, &b);
+    assert!(total <= 2000);
+    assert!(2000 - total < 3);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+pub fn test_shares_issued_once() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    create_share_token(&env, &contract, &owner);
+    contract.issue_shares(&owner, &1_000);
+    contract.issue_shares(&owner, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+pub fn test_transfer_shares_above_balance() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    let shares = create_share_token(&env, &contract, &owner);
+    contract.issue_shares(&owner, &1_000);
+    shares.transfer(&owner, &investor, &1_001);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+pub fn test_shares_and_beneficiaries_are_exclusive() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    create_share_token(&env, &contract, &owner);
+    contract.issue_shares(&owner, &1_000);
+    contract.set_beneficiaries(&owner, &vec![&env, Beneficiary { address: owner.clone(), share_bps: 10_000 }]);
+}
+
+#[test]
+pub fn test_mileage_overage_distributed_to_shareholders() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &0);
+    contract.set_car_deposit(&owner, &1000);
+    contract.set_mileage_policy(&owner, &100, &10);
+    let shares = create_share_token(&env, &contract, &owner);
+    contract.issue_shares(&owner, &1_000);
+    shares.transfer(&owner, &investor, &500);
+
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    let mut pickup = handover_report(&env, 1);
+    pickup.odometer = Some(10_000);
+    contract.confirm_pickup(&rental_id, &pickup);
+
+    // 100 km de excedente × 10 = 1000, cobrados del depósito
+    let mut ret = handover_report(&env, 1);
+    ret.odometer = Some(10_400);
+    contract.confirm_return(&rental_id, &ret);
+
+    assert_eq!(contract.claimable_revenue(&owner, &investor), (4500 + 1000) / 2);
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    assert_eq!(car.available_to_withdraw, 0);
+}
+
+#[test]
+pub fn test_shares_are_a_sep41_token() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    let shares = create_share_token(&env, &contract, &owner);
+    contract.issue_shares(&owner, &1_000);
+
+    assert_eq!(contract.get_car_shares(&owner).token, shares.address);
+    assert_eq!(shares.total_supply(), 1_000);
+
+    // Cualquier cliente SEP-41 puede operar con las participaciones
+    let client = token::Client::new(&env, &shares.address);
+    client.approve(&owner, &marketplace, &300, &(env.ledger().sequence() + 100));
+    client.transfer_from(&marketplace, &owner, &investor, &300);
+
+    assert_eq!(client.balance(&owner), 700);
+    assert_eq!(client.balance(&investor), 300);
+    assert_eq!(client.allowance(&owner, &marketplace), 0);
+}
+
+#[test]
+pub fn test_burned_shares_stop_earning() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1000, &0);
+    let shares = create_share_token(&env, &contract, &owner);
+    contract.issue_shares(&owner, &100);
+    shares.transfer(&owner, &investor, &50);
+    shares.burn(&owner, &50);
+
+    let rental_id = contract.rental(&renter, &owner, &1, &1000, &RentalOptions::default(), &None);
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.confirm_return(&rental_id, &handover_report(&env, 1));
+
+    assert_eq!(contract.claimable_revenue(&owner, &owner), 0);
+    assert_eq!(contract.claimable_revenue(&owner, &investor), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+pub fn test_issue_requires_registered_token() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.issue_shares(&owner, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+pub fn test_share_token_bound_to_car() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let other_owner = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.add_car(&other_owner, &1500, &10);
+
+    let shares = create_share_token(&env, &contract, &other_owner);
+    contract.register_share_token(&owner, &shares.address);
+}
+
+#[test]
+pub fn test_only_share_token_settles_holders() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let attacker = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    create_share_token(&env, &contract, &owner);
+    contract.issue_shares(&owner, &1_000);
+
+    env.set_auths(&[]);
+    assert!(contract
+        .try_settle_share_holders(&owner, &vec![&env, (attacker, 1_000_000)])
+        .is_err());
+}
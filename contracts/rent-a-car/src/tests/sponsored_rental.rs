@@ -0,0 +1,40 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{storage::structs::rental_options::RentalOptions, tests::config::contract::ContractTest};
+
+#[test]
+pub fn test_sponsored_rental_uses_allowance() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let (token_client, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+    token_client.approve(&renter, &relayer, &5000, &1000);
+
+    contract.add_car(&owner, &1500, &10);
+    let rental_id = contract.sponsored_rental(&relayer, &renter, &owner, &3, &4500, &RentalOptions::default());
+
+    let rental = contract.get_rental(&rental_id);
+    assert_eq!(rental.renter, renter);
+    assert_eq!(rental.payer, renter);
+    assert_eq!(token_client.balance(&renter), 10_000 - 4950);
+    assert_eq!(token_client.allowance(&renter, &relayer), 50);
+}
+
+#[test]
+#[should_panic]
+pub fn test_sponsored_rental_without_allowance_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.sponsored_rental(&relayer, &renter, &owner, &3, &4500, &RentalOptions::default());
+}
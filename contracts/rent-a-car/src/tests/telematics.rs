@@ -0,0 +1,114 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Vec,
+};
+use crate::{
+    storage::{
+        structs::{rental_options::RentalOptions, telematics::TelematicsStatus},
+        types::{car_status::CarStatus, rental_status::RentalStatus},
+    },
+    tests::config::{contract::ContractTest, utils::handover_report},
+};
+
+fn status(odometer: u64, fault_codes: Vec<u32>) -> TelematicsStatus {
+    TelematicsStatus {
+        location_zone: 7,
+        odometer,
+        fuel_level: 80,
+        fault_codes,
+    }
+}
+
+#[test]
+pub fn test_push_telematics_stores_snapshot() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let reporter = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_telematics_reporter(&owner, &reporter);
+
+    let update = status(12_000, Vec::new(&env));
+    contract.push_telematics(&owner, &update);
+
+    assert_eq!(contract.get_telematics(&owner).status, update);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+}
+
+#[test]
+pub fn test_fault_code_moves_car_to_maintenance() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let reporter = Address::generate(&env);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_telematics_reporter(&owner, &reporter);
+
+    contract.push_telematics(&owner, &status(12_000, vec![&env, 301]));
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Maintenance);
+
+    contract.clear_maintenance(&owner);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+}
+
+#[test]
+pub fn test_telematics_odometer_feeds_active_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let reporter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_telematics_reporter(&owner, &reporter);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    contract.push_telematics(&owner, &status(12_000, Vec::new(&env)));
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+    contract.push_telematics(&owner, &status(12_250, Vec::new(&env)));
+
+    let readings = contract.get_odometer_readings(&rental_id);
+    assert_eq!(readings.start, Some(12_000));
+    assert_eq!(readings.end, Some(12_250));
+}
+
+#[test]
+pub fn test_push_telematics_during_disputed_rental() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let reporter = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_car_deposit(&owner, &3000);
+    contract.set_telematics_reporter(&owner, &reporter);
+    let rental_id = contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+
+    contract.push_telematics(&owner, &status(12_000, Vec::new(&env)));
+    contract.confirm_pickup(&rental_id, &handover_report(&env, 1));
+
+    env.ledger().with_mut(|li| li.timestamp += 10 * 86_400);
+    contract.settle_overdue(&keeper, &rental_id);
+    contract.push_telematics(&owner, &status(12_400, Vec::new(&env)));
+    contract.file_claim(&rental_id, &2000, &BytesN::from_array(&env, &[7; 32]));
+
+    // El alquiler en disputa no bloquea el snapshot, pero su kilometraje queda congelado
+    let update = status(12_900, Vec::new(&env));
+    contract.push_telematics(&owner, &update);
+
+    assert_eq!(contract.get_telematics(&owner).status, update);
+    assert_eq!(contract.get_rental(&rental_id).status, RentalStatus::Disputed);
+    assert_eq!(contract.get_odometer_readings(&rental_id).end, Some(12_400));
+}
@@ -0,0 +1,90 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::{storage::structs::rental_options::RentalOptions, tests::config::contract::ContractTest};
+
+#[test]
+pub fn test_verified_renter_can_rent_restricted_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_requires_verification(&owner, &true);
+    contract.add_verifier(&verifier);
+    contract.register_attestation(&verifier, &renter, &1000);
+
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+    assert_eq!(contract.get_attestation(&renter).verifier, verifier);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_unverified_renter_cannot_rent_restricted_car() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_requires_verification(&owner, &true);
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_expired_attestation_is_rejected() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_requires_verification(&owner, &true);
+    contract.add_verifier(&verifier);
+    contract.register_attestation(&verifier, &renter, &1000);
+
+    env.ledger().set_timestamp(1000);
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_revoked_attestation_is_rejected() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000);
+
+    contract.add_car(&owner, &1500, &10);
+    contract.set_requires_verification(&owner, &true);
+    contract.add_verifier(&verifier);
+    contract.register_attestation(&verifier, &renter, &1000);
+    contract.revoke_attestation(&verifier, &renter);
+
+    contract.rental(&renter, &owner, &3, &4500, &RentalOptions::default(), &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_unknown_verifier_cannot_attest() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    env.mock_all_auths();
+
+    let renter = Address::generate(&env);
+    contract.register_attestation(&Address::generate(&env), &renter, &1000);
+}
\ No newline at end of file